@@ -0,0 +1,29 @@
+//! Compare one write per record against batched writes to a file
+//!
+//! Run with `cargo bench --bench batch`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rust_telemetry::{clear_output, info, reset_for_bench, set_output, BatchWriter};
+use std::fs::File;
+
+fn bench_file(c: &mut Criterion, name: &str, install: impl FnOnce(File)) {
+    let path = std::env::temp_dir().join(format!("rust-telemetry-bench-{name}.log"));
+    reset_for_bench();
+    install(File::create(&path).unwrap());
+    c.bench_function(name, |b| b.iter(|| info!("record {}", black_box(7))));
+    clear_output();
+    let _ = std::fs::remove_file(path);
+}
+
+fn unbatched(c: &mut Criterion) {
+    bench_file(c, "unbatched", set_output);
+}
+
+fn batched(c: &mut Criterion) {
+    bench_file(c, "batched x64", |file| {
+        set_output(BatchWriter::new(file, 64))
+    });
+}
+
+criterion_group!(benches, unbatched, batched);
+criterion_main!(benches);
@@ -0,0 +1,26 @@
+//! Compare logging throughput with precise and coarse (cached) timestamps
+//!
+//! Run with `cargo bench --bench coarse_time`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rust_telemetry::{clear_coarse_time, info, reset_for_bench, set_coarse_time};
+use std::time::Duration;
+
+fn precise(c: &mut Criterion) {
+    reset_for_bench();
+    c.bench_function("precise time", |b| {
+        b.iter(|| info!("request {} took {}ms", black_box(7), black_box(12)))
+    });
+}
+
+fn coarse(c: &mut Criterion) {
+    reset_for_bench();
+    set_coarse_time(Duration::from_millis(1));
+    c.bench_function("coarse time 1ms", |b| {
+        b.iter(|| info!("request {} took {}ms", black_box(7), black_box(12)))
+    });
+    clear_coarse_time();
+}
+
+criterion_group!(benches, precise, coarse);
+criterion_main!(benches);
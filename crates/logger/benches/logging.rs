@@ -0,0 +1,64 @@
+//! Baseline costs of common logging calls, with output discarded
+//!
+//! Run with `cargo bench --bench logging`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rust_telemetry::{debug, info, log_kv, reset_for_bench, set_max_level, Field, Level};
+use std::thread;
+
+fn disabled(c: &mut Criterion) {
+    reset_for_bench();
+    set_max_level(Level::Warn);
+    c.bench_function("disabled level", |b| {
+        b.iter(|| debug!("request {} took {}ms", black_box(7), black_box(12)))
+    });
+}
+
+fn literal(c: &mut Criterion) {
+    reset_for_bench();
+    c.bench_function("literal info", |b| b.iter(|| info!("request handled")));
+}
+
+fn formatted(c: &mut Criterion) {
+    reset_for_bench();
+    c.bench_function("formatted info", |b| {
+        b.iter(|| info!("request {} took {}ms", black_box(7), black_box(12)))
+    });
+}
+
+fn fields(c: &mut Criterion) {
+    reset_for_bench();
+    c.bench_function("structured fields", |b| {
+        b.iter(|| {
+            let fields: [Field; 3] = [
+                ("status", &black_box(200)),
+                ("path", &"/users"),
+                ("latency_ms", &black_box(12)),
+            ];
+            log_kv(Level::Info, "request", &fields);
+        })
+    });
+}
+
+fn contention(c: &mut Criterion) {
+    const THREADS: usize = 4;
+    const RECORDS: usize = 1_000;
+
+    reset_for_bench();
+    c.bench_function("4 threads x 1000 records", |b| {
+        b.iter(|| {
+            thread::scope(|scope| {
+                for t in 0..THREADS {
+                    scope.spawn(move || {
+                        for i in 0..RECORDS {
+                            info!("thread {} record {}", t, black_box(i));
+                        }
+                    });
+                }
+            })
+        })
+    });
+}
+
+criterion_group!(benches, disabled, literal, formatted, fields, contention);
+criterion_main!(benches);
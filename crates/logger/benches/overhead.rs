@@ -0,0 +1,37 @@
+//! Compare the cost of a logging call when disabled, discarded and written
+//!
+//! Run with `cargo bench --bench overhead`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rust_telemetry::{
+    clear_output, info, reset_for_bench, set_max_level, set_output, FileWriter, Level,
+};
+
+fn off(c: &mut Criterion) {
+    reset_for_bench();
+    set_max_level(Level::Off);
+    c.bench_function("level off", |b| {
+        b.iter(|| info!("request {} took {}ms", black_box(7), black_box(12)))
+    });
+}
+
+fn null_writer(c: &mut Criterion) {
+    reset_for_bench();
+    c.bench_function("null writer", |b| {
+        b.iter(|| info!("request {} took {}ms", black_box(7), black_box(12)))
+    });
+}
+
+fn file_writer(c: &mut Criterion) {
+    let path = std::env::temp_dir().join("rust-telemetry-bench-overhead.log");
+    reset_for_bench();
+    set_output(FileWriter::new(&path).unwrap());
+    c.bench_function("file writer", |b| {
+        b.iter(|| info!("request {} took {}ms", black_box(7), black_box(12)))
+    });
+    clear_output();
+    let _ = std::fs::remove_file(path);
+}
+
+criterion_group!(benches, off, null_writer, file_writer);
+criterion_main!(benches);
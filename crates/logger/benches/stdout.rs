@@ -0,0 +1,44 @@
+//! Compare acquiring stdout on every write against the cached handle
+//!
+//! Run with `cargo bench --bench stdout`. The benchmarked lines share stdout
+//! with criterion's report; the results are also saved under
+//! `target/criterion`.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use rust_telemetry::StdoutWriter;
+use std::io::{self, Write};
+
+const LINE: &[u8] = b"00:00:00.000 \x1b[32minfo\x1b[0m request handled path=/health\n";
+
+/// The previous implementation: look up stdout for every call
+struct PerCallStdout;
+
+impl Write for PerCallStdout {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        io::stdout().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        io::stdout().flush()
+    }
+}
+
+fn bench_writer(c: &mut Criterion, name: &str, mut writer: impl Write) {
+    c.bench_function(name, |b| {
+        b.iter(|| {
+            writer.write_all(LINE).unwrap();
+            writer.flush().unwrap();
+        })
+    });
+}
+
+fn per_call(c: &mut Criterion) {
+    bench_writer(c, "stdout per call", PerCallStdout);
+}
+
+fn cached(c: &mut Criterion) {
+    bench_writer(c, "stdout cached", StdoutWriter::new());
+}
+
+criterion_group!(benches, per_call, cached);
+criterion_main!(benches);
@@ -0,0 +1,87 @@
+use crate::clock;
+use crate::level::Level;
+use crate::utils::lock;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+
+/// Length of the rate window
+const WINDOW: Duration = Duration::from_secs(1);
+
+/// Whether the effective level adapts to the log rate
+static ADAPTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Records per second above which the level is raised
+static RATE_THRESHOLD: AtomicU64 = AtomicU64::new(0);
+
+/// Sliding-window record counter
+struct RateWindow {
+    /// Start of the current window
+    start: SystemTime,
+    /// Records counted in the previous window
+    previous: u64,
+    /// Records counted in the current window
+    current: u64,
+}
+
+static WINDOW_STATE: Mutex<Option<RateWindow>> = Mutex::new(None);
+
+/// Raise the effective level while the log rate exceeds `rate_threshold` per second
+///
+/// Above the threshold debug records are dropped, and above twice the
+/// threshold info records too; the level relaxes as the rate subsides.
+/// Errors are never dropped. The rate counts every record that passes the
+/// configured level filters, over a one-second sliding window.
+pub fn set_adaptive_level(enabled: bool, rate_threshold: u64) {
+    *lock(&WINDOW_STATE) = None;
+    RATE_THRESHOLD.store(rate_threshold, Ordering::Relaxed);
+    ADAPTIVE.store(enabled, Ordering::Relaxed);
+}
+
+/// Count a record at `level`, returning whether the current rate sheds it
+pub(crate) fn sheds(level: Level) -> bool {
+    if !ADAPTIVE.load(Ordering::Relaxed) {
+        return false;
+    }
+
+    let rate = count_record(clock::now());
+    let threshold = RATE_THRESHOLD.load(Ordering::Relaxed);
+    let floor = if rate > threshold.saturating_mul(2) {
+        Level::Warn
+    } else if rate > threshold {
+        Level::Info
+    } else {
+        return false;
+    };
+    level < floor
+}
+
+/// Count a record at `now`, returning the estimated records over the last window
+fn count_record(now: SystemTime) -> u64 {
+    let mut state = lock(&WINDOW_STATE);
+    let window = state.get_or_insert(RateWindow {
+        start: now,
+        previous: 0,
+        current: 0,
+    });
+
+    // A clock moving backwards restarts the window
+    let mut elapsed = now.duration_since(window.start).unwrap_or(WINDOW * 2);
+    if elapsed >= WINDOW * 2 {
+        *window = RateWindow {
+            start: now,
+            previous: 0,
+            current: 0,
+        };
+        elapsed = Duration::ZERO;
+    } else if elapsed >= WINDOW {
+        window.start += WINDOW;
+        window.previous = window.current;
+        window.current = 0;
+        elapsed -= WINDOW;
+    }
+
+    window.current += 1;
+    let remaining = 1.0 - elapsed.as_secs_f64() / WINDOW.as_secs_f64();
+    window.current + (window.previous as f64 * remaining) as u64
+}
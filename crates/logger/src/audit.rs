@@ -0,0 +1,55 @@
+use crate::config::{format_line, write_line};
+use crate::failures::{record_failure, record_success};
+use crate::format::PanicSafe;
+use crate::record::{Field, Record};
+use crate::sanitize::{sanitize_message, Sanitized};
+use crate::utils::lock;
+use crate::writers::RecordWriter;
+use std::sync::Mutex;
+
+/// Dedicated audit writer (None = the normal output)
+static AUDIT_OUTPUT: Mutex<Option<Box<dyn RecordWriter>>> = Mutex::new(None);
+
+/// Send audit records to `writer`, separately from normal logs
+pub fn set_audit_output<W: RecordWriter + 'static>(writer: W) {
+    *lock(&AUDIT_OUTPUT) = Some(Box::new(writer));
+}
+
+/// Send audit records back to the normal output
+pub fn clear_audit_output() {
+    *lock(&AUDIT_OUTPUT) = None;
+}
+
+/// Write an audit record, bypassing every level filter and sampling
+///
+/// The record is formatted like any other, with the same message and field
+/// escaping, and written to the audit output (see `set_audit_output`), which
+/// is flushed after every record. Hooks, subscribers and stats do not see
+/// audit records.
+pub fn audit(record: &Record) {
+    let message = sanitize_message(record.message());
+    let guarded: Vec<_> = record
+        .fields()
+        .iter()
+        .map(|(key, value)| (*key, Sanitized(PanicSafe(*value))))
+        .collect();
+    let fields: Vec<Field> = guarded
+        .iter()
+        .map(|(key, value)| (*key, value as _))
+        .collect();
+    let record = &record.with_message(&message).with_fields(&fields);
+    let line = format_line(record);
+    let mut output = lock(&AUDIT_OUTPUT);
+    let Some(writer) = output.as_mut() else {
+        drop(output);
+        return write_line(record, &line, None);
+    };
+
+    match writer
+        .write_record(record, line.as_bytes())
+        .and_then(|()| writer.flush())
+    {
+        Ok(()) => record_success(),
+        Err(err) => record_failure(&line, &err),
+    }
+}
@@ -0,0 +1,100 @@
+use crate::adaptive::set_adaptive_level;
+use crate::audit::clear_audit_output;
+use crate::clock::{clear_clock, clear_coarse_time};
+use crate::config::{
+    clear_output_local, clear_timestamp, set_compact_timestamps, set_line_terminator, set_output,
+    set_timestamp_format, set_timestamp_levels, set_timestamp_position, set_timestamp_subsecond,
+    TimestampFormat, TimestampPosition,
+};
+use crate::deferred::clear_deferred_dump;
+use crate::diagnostics::set_warn_on_late_init;
+use crate::failures::{
+    clear_failure_alert, clear_internal_error_sink, set_stderr_fallback, set_write_failure_mode,
+    WriteFailureMode,
+};
+use crate::filter::clear_module_levels;
+use crate::flush::clear_flush_interval;
+use crate::format::{
+    clear_field_layout, clear_level_names, set_field_separator, set_format, set_kv_separator,
+    set_text_field_style, set_theme, Format, TextFieldStyle, Theme,
+};
+use crate::group::set_log_groups;
+use crate::hooks::clear_on_record;
+use crate::level::{set_max_level, Level};
+use crate::loggers::{
+    set_dedup_location, set_max_fields, set_show_delta, set_show_seq, set_skip_empty,
+};
+use crate::optional::{set_none_rendering, NoneRendering};
+use crate::record_id::set_show_record_id;
+use crate::sampling::set_sampling_rules;
+use crate::sanitize::{
+    set_escape_newlines, set_escape_nul, set_sanitize_messages, set_trim_message,
+};
+use crate::stats::{reset_max_observed_level, stats};
+use crate::thread::{set_show_thread, set_thread_id_format, ThreadIdFormat};
+use crate::writers::{set_oversized_policy, NullWriter, OversizedPolicy};
+
+/// Reset the logger to its defaults with output discarded, for benchmarks
+///
+/// Installs a `NullWriter`, restores every global setting (level, filters,
+/// sampling, format, timestamps, escaping, hooks, failure handling, ...) to
+/// its default, zeroes the stats, and clears this thread's output override,
+/// so every benchmark starts from the same baseline whatever ran before it.
+/// Live subscribers and the CEF device are left alone.
+pub fn reset_for_bench() {
+    clear_flush_interval();
+    clear_coarse_time();
+    clear_output_local();
+    set_output(NullWriter);
+    clear_audit_output();
+
+    set_max_level(Level::Debug);
+    clear_module_levels();
+    set_sampling_rules(Vec::new());
+    set_adaptive_level(false, 0);
+    clear_deferred_dump();
+    set_skip_empty(false);
+
+    set_format(Format::Text);
+    set_theme(Theme::DEFAULT);
+    clear_level_names();
+    clear_field_layout();
+    set_field_separator(" ");
+    set_kv_separator("=");
+    set_text_field_style(TextFieldStyle::KeyValue);
+    set_max_fields(usize::MAX);
+    set_none_rendering(NoneRendering::Literal);
+    set_log_groups(false);
+
+    clear_timestamp();
+    clear_clock();
+    set_timestamp_format(TimestampFormat::TimeOfDay);
+    set_timestamp_position(TimestampPosition::Prefix);
+    set_timestamp_subsecond(true);
+    set_timestamp_levels(Level::Debug);
+    set_compact_timestamps(false);
+    set_line_terminator("\n");
+
+    set_show_seq(false);
+    set_show_delta(false);
+    set_show_thread(None);
+    set_thread_id_format(ThreadIdFormat::Decimal);
+    set_show_record_id(false);
+    set_dedup_location(false);
+
+    set_sanitize_messages(false);
+    set_escape_newlines(true);
+    set_escape_nul(true);
+    set_trim_message(true);
+    set_oversized_policy(OversizedPolicy::Truncate);
+
+    clear_on_record();
+    set_write_failure_mode(WriteFailureMode::Continue, 0);
+    clear_failure_alert();
+    set_stderr_fallback(false);
+    clear_internal_error_sink();
+    set_warn_on_late_init(false);
+
+    stats().reset();
+    reset_max_observed_level();
+}
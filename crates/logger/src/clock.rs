@@ -0,0 +1,150 @@
+use crate::utils::{lock, read, write};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex, PoisonError, RwLock};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, SystemTime};
+
+/// Source of the current time for timestamps and time-based rotation
+pub trait Clock: Send + Sync {
+    fn now(&self) -> SystemTime;
+}
+
+/// Clock reading the system time
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// Manually controlled clock (useful for testing)
+/// Clones share the same time, so a handle can be kept after `set_clock`
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    time: Arc<Mutex<SystemTime>>,
+}
+
+impl MockClock {
+    pub fn new(time: SystemTime) -> Self {
+        Self {
+            time: Arc::new(Mutex::new(time)),
+        }
+    }
+
+    /// Set the current time
+    pub fn set(&self, time: SystemTime) {
+        *lock(&self.time) = time;
+    }
+
+    /// Move the current time forward
+    pub fn advance(&self, by: Duration) {
+        *lock(&self.time) += by;
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> SystemTime {
+        *lock(&self.time)
+    }
+}
+
+/// Global clock (None = system time)
+static CLOCK: RwLock<Option<Arc<dyn Clock>>> = RwLock::new(None);
+
+/// Use a custom clock for timestamps and time-based rotation
+pub fn set_clock(clock: Box<dyn Clock>) {
+    let mut c = write(&CLOCK);
+    *c = Some(Arc::from(clock));
+}
+
+/// Freeze the clock at `time` (clear with `clear_clock`)
+///
+/// Unlike `set_timestamp`, the timestamp is still rendered by the configured
+/// timestamp format, so tests exercise the formatting itself.
+pub fn set_fixed_time(time: SystemTime) {
+    set_clock(Box::new(MockClock::new(time)));
+}
+
+/// Clear custom clock, use the system time
+pub fn clear_clock() {
+    let mut c = write(&CLOCK);
+    *c = None;
+}
+
+/// Refresh interval of the cached time (None = coarse time off)
+static COARSE_INTERVAL: Mutex<Option<Duration>> = Mutex::new(None);
+
+/// Wakes the updater when the interval changes
+static COARSE_WAKE: Condvar = Condvar::new();
+
+/// Handle of the background time updater
+static COARSE_WORKER: Mutex<Option<JoinHandle<()>>> = Mutex::new(None);
+
+/// Whether the system time is read from the cache
+static COARSE_ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Cached system time, in nanoseconds since the Unix epoch
+static COARSE_NANOS: AtomicU64 = AtomicU64::new(0);
+
+/// Read the system time from a cache refreshed every `resolution`
+///
+/// Saves a clock read per log line for high-rate logging, at the cost of
+/// timestamps lagging by up to `resolution`. The cache is refreshed by a
+/// background thread. A custom clock set with `set_clock` still takes
+/// precedence.
+pub fn set_coarse_time(resolution: Duration) {
+    refresh_coarse_time();
+    *lock(&COARSE_INTERVAL) = Some(resolution);
+    COARSE_ACTIVE.store(true, Ordering::Relaxed);
+    COARSE_WAKE.notify_all();
+
+    let mut worker = lock(&COARSE_WORKER);
+    if worker.is_none() {
+        *worker = Some(thread::spawn(run_coarse_time));
+    }
+}
+
+/// Read the system time on every log line again, stopping the updater
+pub fn clear_coarse_time() {
+    COARSE_ACTIVE.store(false, Ordering::Relaxed);
+    *lock(&COARSE_INTERVAL) = None;
+    COARSE_WAKE.notify_all();
+
+    if let Some(worker) = lock(&COARSE_WORKER).take() {
+        let _ = worker.join();
+    }
+}
+
+/// Store the current system time in the cache
+fn refresh_coarse_time() {
+    let nanos = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+    COARSE_NANOS.store(nanos as u64, Ordering::Relaxed);
+}
+
+/// Updater loop: refresh the cache on every timeout until coarse time is cleared
+fn run_coarse_time() {
+    let mut interval = lock(&COARSE_INTERVAL);
+    while let Some(period) = *interval {
+        let (guard, _) = COARSE_WAKE
+            .wait_timeout(interval, period)
+            .unwrap_or_else(PoisonError::into_inner);
+        interval = guard;
+        refresh_coarse_time();
+    }
+}
+
+/// Current time from the configured clock
+pub(crate) fn now() -> SystemTime {
+    match read(&CLOCK).as_ref() {
+        Some(clock) => clock.now(),
+        None if COARSE_ACTIVE.load(Ordering::Relaxed) => {
+            SystemTime::UNIX_EPOCH + Duration::from_nanos(COARSE_NANOS.load(Ordering::Relaxed))
+        }
+        None => SystemTime::now(),
+    }
+}
@@ -0,0 +1,80 @@
+use crate::config::flush_output;
+use crate::filter::{enabled, most_verbose_level};
+use crate::format::safe::format_safe;
+use crate::level::Level;
+use crate::loggers::log;
+use crate::record::Record;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Backend forwarding `log` crate records into this logger
+struct Bridge;
+
+/// Whether the bridge is installed (so level changes are mirrored)
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+static BRIDGE: Bridge = Bridge;
+
+/// Map a `log` level (`Trace` folds into `Debug`)
+fn from_log(level: log::Level) -> Level {
+    match level {
+        log::Level::Trace | log::Level::Debug => Level::Debug,
+        log::Level::Info => Level::Info,
+        log::Level::Warn => Level::Warn,
+        log::Level::Error => Level::Error,
+    }
+}
+
+/// Map a minimum level to the `log` filter letting the same records through
+fn to_log_filter(level: Level) -> log::LevelFilter {
+    match level {
+        Level::Debug => log::LevelFilter::Trace,
+        Level::Info => log::LevelFilter::Info,
+        Level::Warn => log::LevelFilter::Warn,
+        Level::Error => log::LevelFilter::Error,
+        Level::Off => log::LevelFilter::Off,
+    }
+}
+
+impl log::Log for Bridge {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        enabled(metadata.target(), from_log(metadata.level()))
+    }
+
+    fn log(&self, record: &log::Record) {
+        let message = format_safe(*record.args());
+        let mut ours = Record::new(from_log(record.level()), &message).with_target(record.target());
+        if let Some(module_path) = record.module_path() {
+            ours = ours.with_module_path(module_path);
+        }
+        if let (Some(file), Some(line)) = (record.file(), record.line()) {
+            ours = ours.with_location(file, line);
+        }
+        log(&ours);
+    }
+
+    fn flush(&self) {
+        flush_output();
+    }
+}
+
+/// Route records from the `log` crate facade into this logger
+///
+/// `log::max_level()` then follows the most verbose of `set_max_level` and
+/// the per-target overrides from `set_module_level` and `set_filter`, so the
+/// facade never drops a record this logger would write. The per-target
+/// overrides themselves apply inside the bridge.
+pub fn init_log_compat() -> Result<(), log::SetLoggerError> {
+    log::set_logger(&BRIDGE)?;
+    INSTALLED.store(true, Ordering::Relaxed);
+    sync_max_level();
+    Ok(())
+}
+
+/// Mirror the level filters to the `log` facade (once the bridge is installed)
+///
+/// Called whenever the global level or a per-target override changes.
+pub(crate) fn sync_max_level() {
+    if INSTALLED.load(Ordering::Relaxed) {
+        log::set_max_level(to_log_filter(most_verbose_level()));
+    }
+}
@@ -1,65 +1,447 @@
-use crate::writers::StdoutWriter;
-use std::io::Write;
-use std::sync::{LazyLock, Mutex};
-use std::time::SystemTime;
+use crate::clock::now;
+use crate::deferred::{defer, write_all, Deferral};
+use crate::failures::{record_failure, record_success};
+use crate::flush::background_flush_active;
+use crate::format::formatter;
+use crate::level::Level;
+use crate::record::Record;
+use crate::utils::{civil_from_days, lock, read, strip_ansi, write};
+use crate::writers::{MemoryWriter, RecordWriter, StdoutWriter};
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::io;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicU8, Ordering};
+use std::sync::{LazyLock, Mutex, RwLock};
+use std::time::{Duration, SystemTime};
 
 /// Global writer for log output (defaults to stdout)
-static WRITER: LazyLock<Mutex<Box<dyn Write + Send>>> =
+static WRITER: LazyLock<Mutex<Box<dyn RecordWriter>>> =
     LazyLock::new(|| Mutex::new(Box::new(StdoutWriter::new())));
 
+thread_local! {
+    /// Per-thread writer overriding `WRITER` (e.g. for parallel tests)
+    static LOCAL_WRITER: RefCell<Option<Box<dyn RecordWriter>>> = const { RefCell::new(None) };
+}
+
+/// Bytes successfully written to `WRITER` since it was installed
+static BYTES_WRITTEN: AtomicU64 = AtomicU64::new(0);
+
+/// Whether `WRITER` reported a broken pipe (writes are skipped until replaced)
+static OUTPUT_CLOSED: AtomicBool = AtomicBool::new(false);
+
+/// Type name of the configured writer (for diagnostics)
+static WRITER_NAME: RwLock<&'static str> = RwLock::new("StdoutWriter");
+
 /// Global timestamp override (None = use current time)
 static TIMESTAMP: Mutex<Option<String>> = Mutex::new(None);
 
+/// Where the timestamp is placed in a log line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimestampPosition {
+    /// Before the level (default)
+    Prefix,
+    /// At the end of the first line
+    Suffix,
+    /// Omitted (e.g. when the sink adds its own)
+    None,
+}
+
+/// Global timestamp placement
+static TIMESTAMP_POSITION: RwLock<TimestampPosition> = RwLock::new(TimestampPosition::Prefix);
+
+/// How the current time is rendered in a log line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimestampFormat {
+    /// `HH:MM:SS.mmm` in UTC (default)
+    ///
+    /// Carries no date, so it wraps to `00:00:00.000` at midnight; use
+    /// `DateTime` or `Rfc3339` when comparing times across days.
+    TimeOfDay,
+    /// `YYYY-MM-DD HH:MM:SS.mmm` in UTC
+    DateTime,
+    /// `YYYY-MM-DDTHH:MM:SS.mmmZ`
+    Rfc3339,
+}
+
+/// Global timestamp format
+static TIMESTAMP_FORMAT: RwLock<TimestampFormat> = RwLock::new(TimestampFormat::TimeOfDay);
+
+/// Whether timestamps include milliseconds
+static TIMESTAMP_SUBSECOND: AtomicBool = AtomicBool::new(true);
+
+/// Least severe level that gets a timestamp
+static TIMESTAMP_LEVEL: AtomicU8 = AtomicU8::new(Level::Debug as u8);
+
+/// Whether repeated timestamps are replaced with padding
+static COMPACT_TIMESTAMPS: AtomicBool = AtomicBool::new(false);
+
+/// Last rendered timestamp (for compact timestamps)
+static LAST_TIMESTAMP: Mutex<String> = Mutex::new(String::new());
+
+/// Global line terminator appended to every log line
+static LINE_TERMINATOR: RwLock<Cow<'static, str>> = RwLock::new(Cow::Borrowed("\n"));
+
 /// Set a custom output target for logs
-pub fn set_output<W: Write + Send + 'static>(writer: W) {
-    let mut w = WRITER.lock().unwrap();
-    *w = Box::new(writer);
+pub fn set_output<W: RecordWriter + 'static>(writer: W) {
+    replace_output(Box::new(writer), std::any::type_name::<W>());
 }
 
 /// Clear custom output, revert to stdout
 pub fn clear_output() {
-    let mut w = WRITER.lock().unwrap();
-    *w = Box::new(StdoutWriter::new());
+    replace_output(Box::new(StdoutWriter::new()), "StdoutWriter");
+}
+
+/// Replace the output with `writer`, returning the previous one
+///
+/// Pass the returned writer back to `swap_output` (or `set_output`) to
+/// restore it.
+pub fn swap_output<W: RecordWriter + 'static>(writer: W) -> Box<dyn RecordWriter> {
+    replace_output(Box::new(writer), std::any::type_name::<W>()).0
+}
+
+/// Run `body` with logs sent to `writer`, restoring the previous output afterwards
+///
+/// The previous output is restored even if `body` panics.
+pub fn with_output<W: RecordWriter + 'static, R>(writer: W, body: impl FnOnce() -> R) -> R {
+    let previous = replace_output(Box::new(writer), std::any::type_name::<W>());
+    let _restore = RestoreOutput(Some(previous));
+    body()
+}
+
+/// Puts back the previous output when a `with_output` scope ends
+struct RestoreOutput(Option<(Box<dyn RecordWriter>, &'static str)>);
+
+impl Drop for RestoreOutput {
+    fn drop(&mut self) {
+        if let Some((writer, name)) = self.0.take() {
+            replace_output(writer, name);
+        }
+    }
+}
+
+/// Install `writer` as the global output, returning the previous writer and its name
+fn replace_output(
+    writer: Box<dyn RecordWriter>,
+    name: &'static str,
+) -> (Box<dyn RecordWriter>, &'static str) {
+    let previous = std::mem::replace(&mut *lock(&WRITER), writer);
+    let previous_name = std::mem::replace(&mut *write(&WRITER_NAME), name);
+    BYTES_WRITTEN.store(0, Ordering::Relaxed);
+    OUTPUT_CLOSED.store(false, Ordering::Relaxed);
+    record_success();
+    (previous, previous_name)
+}
+
+/// Bytes of formatted output written to the global output
+///
+/// Counts whole lines (including the terminator) as handed to the writer,
+/// and resets when the output is replaced. Thread-local outputs and `log_to`
+/// are not counted.
+pub fn bytes_written() -> u64 {
+    BYTES_WRITTEN.load(Ordering::Relaxed)
+}
+
+/// Send this thread's logs to `writer` instead of the global output
+pub fn set_output_local<W: RecordWriter + 'static>(writer: W) {
+    LOCAL_WRITER.with(|local| *local.borrow_mut() = Some(Box::new(writer)));
+}
+
+/// Clear this thread's output override, reverting to the global output
+pub fn clear_output_local() {
+    LOCAL_WRITER.with(|local| *local.borrow_mut() = None);
+}
+
+/// Run `body` with this thread's logs captured, returning the output
+///
+/// Other threads keep logging to their own outputs, so tests using this can
+/// run in parallel. ANSI codes are stripped as with `MemoryWriter`. This
+/// thread's previous output is restored even if `body` panics.
+pub fn with_captured_output(body: impl FnOnce()) -> String {
+    let buffer = MemoryWriter::new();
+    let previous = LOCAL_WRITER.with(|local| local.replace(Some(Box::new(buffer.writer()))));
+    let restore = RestoreLocalOutput(previous);
+    body();
+    drop(restore);
+    buffer.contents()
+}
+
+/// Puts back this thread's previous output when a `with_captured_output` scope ends
+struct RestoreLocalOutput(Option<Box<dyn RecordWriter>>);
+
+impl Drop for RestoreLocalOutput {
+    fn drop(&mut self) {
+        let previous = self.0.take();
+        LOCAL_WRITER.with(|local| *local.borrow_mut() = previous);
+    }
+}
+
+/// Type name of the configured writer
+pub(crate) fn writer_name() -> &'static str {
+    *read(&WRITER_NAME)
+}
+
+/// Flush the configured output
+pub(crate) fn flush_output() {
+    let mut writer = lock(&WRITER);
+    let _ = writer.flush();
+}
+
+/// Shut down the configured output, waiting at most `timeout`
+pub(crate) fn shutdown_output(timeout: Duration) -> bool {
+    lock(&WRITER).shutdown(timeout)
 }
 
 /// Set a fixed timestamp for all logs (useful for testing)
 pub fn set_timestamp(ts: &str) {
-    let mut t = TIMESTAMP.lock().unwrap();
+    let mut t = lock(&TIMESTAMP);
     *t = Some(ts.to_string());
 }
 
+/// Get the fixed timestamp, if set
+pub(crate) fn fixed_timestamp() -> Option<String> {
+    lock(&TIMESTAMP).clone()
+}
+
 /// Clear fixed timestamp, use current time
 pub fn clear_timestamp() {
-    let mut t = TIMESTAMP.lock().unwrap();
+    let mut t = lock(&TIMESTAMP);
     *t = None;
 }
 
-/// Get timestamp (fixed or current time)
-fn timestamp() -> String {
-    if let Some(ts) = TIMESTAMP.lock().unwrap().as_ref() {
+/// Set where the timestamp is placed in each line
+pub fn set_timestamp_position(position: TimestampPosition) {
+    *write(&TIMESTAMP_POSITION) = position;
+}
+
+/// Set how the current time is rendered
+pub fn set_timestamp_format(format: TimestampFormat) {
+    *write(&TIMESTAMP_FORMAT) = format;
+}
+
+/// Include the `.mmm` millisecond fraction in timestamps (default true)
+pub fn set_timestamp_subsecond(enabled: bool) {
+    TIMESTAMP_SUBSECOND.store(enabled, Ordering::Relaxed);
+}
+
+/// Timestamp only records at or above `level` (default `Level::Debug`, i.e. all)
+///
+/// Lower records are written without a timestamp, which saves space and the
+/// cost of rendering it for chatty debug output.
+pub fn set_timestamp_levels(level: Level) {
+    TIMESTAMP_LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+/// Show the timestamp only on the first of consecutive lines sharing it
+///
+/// Repeats are replaced with spaces of the same width so columns stay aligned.
+pub fn set_compact_timestamps(enabled: bool) {
+    COMPACT_TIMESTAMPS.store(enabled, Ordering::Relaxed);
+    lock(&LAST_TIMESTAMP).clear();
+}
+
+/// Set the terminator appended to every log line (default `"\n"`)
+///
+/// Use `"\r\n"` for CRLF tooling, or `""` for sinks that frame lines themselves.
+pub fn set_line_terminator(terminator: &str) {
+    let mut t = write(&LINE_TERMINATOR);
+    *t = Cow::Owned(terminator.to_string());
+}
+
+/// Get the timestamp format, placement and whether it has milliseconds
+pub(crate) fn timestamp_settings() -> (TimestampFormat, TimestampPosition, bool) {
+    (
+        *read(&TIMESTAMP_FORMAT),
+        *read(&TIMESTAMP_POSITION),
+        TIMESTAMP_SUBSECOND.load(Ordering::Relaxed),
+    )
+}
+
+/// Get the line terminator
+pub(crate) fn line_terminator() -> String {
+    read(&LINE_TERMINATOR).to_string()
+}
+
+/// Get timestamp (fixed, or `now` as time since the Unix epoch)
+fn timestamp(now: Duration) -> String {
+    if let Some(ts) = lock(&TIMESTAMP).as_ref() {
         return ts.clone();
     }
 
-    let now = SystemTime::now()
-        .duration_since(SystemTime::UNIX_EPOCH)
-        .unwrap_or_default();
-
     let secs = now.as_secs();
-    let millis = now.subsec_millis();
+    let fraction = if TIMESTAMP_SUBSECOND.load(Ordering::Relaxed) {
+        format!(".{:03}", now.subsec_millis())
+    } else {
+        String::new()
+    };
 
     let hours = (secs % 86400) / 3600;
     let minutes = (secs % 3600) / 60;
     let seconds = secs % 60;
 
-    format!("{:02}:{:02}:{:02}.{:03}", hours, minutes, seconds, millis)
+    match *read(&TIMESTAMP_FORMAT) {
+        TimestampFormat::TimeOfDay => {
+            format!("{:02}:{:02}:{:02}{}", hours, minutes, seconds, fraction)
+        }
+        TimestampFormat::DateTime => {
+            let (year, month, day) = civil_from_days((secs / 86400) as i64);
+            format!(
+                "{:04}-{:02}-{:02} {:02}:{:02}:{:02}{}",
+                year, month, day, hours, minutes, seconds, fraction
+            )
+        }
+        TimestampFormat::Rfc3339 => {
+            let (year, month, day) = civil_from_days((secs / 86400) as i64);
+            format!(
+                "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}{}Z",
+                year, month, day, hours, minutes, seconds, fraction
+            )
+        }
+    }
 }
 
-/// Write a log line to the configured output
-pub fn write_log(level: &str, color: &str, message: &str) {
-    let time = timestamp();
-    let line = format!("{time} {color}{level}\x1b[0m {message}\n");
+/// Current time as time since the Unix epoch
+fn now_since_epoch() -> Duration {
+    now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default()
+}
 
-    let mut writer = WRITER.lock().unwrap();
-    let _ = writer.write_all(line.as_bytes());
-    let _ = writer.flush();
+/// Render `record`'s time (or the current time) in the configured timestamp format
+pub(crate) fn record_timestamp(record: &Record) -> String {
+    let now = record.time().unwrap_or_else(now_since_epoch);
+    timestamp(now)
+}
+
+/// Blank out `time` if it repeats the previous line's (when compacting)
+fn compact(time: String) -> String {
+    if !COMPACT_TIMESTAMPS.load(Ordering::Relaxed) {
+        return time;
+    }
+
+    let mut last = lock(&LAST_TIMESTAMP);
+    if *last == time {
+        " ".repeat(time.chars().count())
+    } else {
+        last.clone_from(&time);
+        time
+    }
+}
+
+/// Render `record` as a complete line, including timestamp and terminator
+pub(crate) fn format_line(record: &Record) -> String {
+    render_line(record, true)
+}
+
+/// Render `record` as the parts of its line before and after the message
+///
+/// The timestamp is never compacted. None if the format does not write the
+/// message verbatim.
+pub(crate) fn line_around_message(record: &Record) -> Option<(String, String)> {
+    const PLACEHOLDER: &str = "\u{E000}";
+    let line = render_line(&record.with_message(PLACEHOLDER), false);
+    let line = String::from_utf8_lossy(&strip_ansi(line.as_bytes())).into_owned();
+    let (head, tail) = line.split_once(PLACEHOLDER)?;
+    (!tail.contains(PLACEHOLDER)).then(|| (head.to_string(), tail.to_string()))
+}
+
+/// Render a line at the record's time (or now), compacting the timestamp if `compact_time`
+fn render_line(record: &Record, compact_time: bool) -> String {
+    let now = record.time().unwrap_or_else(now_since_epoch);
+    let record = &record.with_time(now);
+
+    let mut line = String::new();
+    let position = if record.level() >= Level::from_u8(TIMESTAMP_LEVEL.load(Ordering::Relaxed)) {
+        *read(&TIMESTAMP_POSITION)
+    } else {
+        TimestampPosition::None
+    };
+    match position {
+        TimestampPosition::Prefix => {
+            let time = timestamp(now);
+            let time = if compact_time { compact(time) } else { time };
+            formatter().format(record, &time, &mut line)
+        }
+        TimestampPosition::Suffix => {
+            formatter().format(record, "", &mut line);
+            let end = line.find('\n').unwrap_or(line.len());
+            line.insert_str(end, &format!(" {}", timestamp(now)));
+        }
+        TimestampPosition::None => formatter().format(record, "", &mut line),
+    }
+    line.push_str(&read(&LINE_TERMINATOR));
+    line
+}
+
+/// Format `record` and write it to the configured output (or hold it for a deferred dump)
+///
+/// `output` overrides the thread-local and global writers for this record.
+pub(crate) fn write_log(record: &Record, output: Option<&mut dyn RecordWriter>) {
+    // Stamp the time once, so writers see the time shown in the line
+    let time = record.time().unwrap_or_else(now_since_epoch);
+    let record = &record.with_time(time);
+    let line = format_line(record);
+    if output.is_some() {
+        return write_line(record, &line, output);
+    }
+
+    match defer(record, line) {
+        Deferral::Inactive(line) => write_line(record, &line, None),
+        Deferral::Held => {}
+        Deferral::Released(pending) => write_all(pending),
+    }
+}
+
+/// Write a formatted `line` to `output`, or the thread-local or global writer
+pub(crate) fn write_line(record: &Record, line: &str, output: Option<&mut dyn RecordWriter>) {
+    let result = match output {
+        Some(writer) => write_to(writer, record, line),
+        None => {
+            let local = LOCAL_WRITER.with(|local| match local.try_borrow_mut() {
+                Ok(mut local) => local
+                    .as_mut()
+                    .map(|writer| write_to(writer.as_mut(), record, line)),
+                Err(_) => None,
+            });
+            match local {
+                Some(result) => result,
+                None => write_global(record, line),
+            }
+        }
+    };
+
+    match result {
+        Ok(()) => record_success(),
+        Err(err) => record_failure(line, &err),
+    }
+}
+
+/// Write one line to the global writer
+///
+/// A broken pipe (e.g. stdout piped into `head`) is reported once, after
+/// which the writer is left alone until the output is replaced.
+fn write_global(record: &Record, line: &str) -> io::Result<()> {
+    if OUTPUT_CLOSED.load(Ordering::Relaxed) {
+        return Ok(());
+    }
+
+    let result = write_to(lock(&WRITER).as_mut(), record, line);
+    match &result {
+        Ok(()) => {
+            BYTES_WRITTEN.fetch_add(line.len() as u64, Ordering::Relaxed);
+        }
+        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => {
+            OUTPUT_CLOSED.store(true, Ordering::Relaxed);
+        }
+        Err(_) => {}
+    }
+    result
+}
+
+/// Write and (unless flushing in the background) flush one line
+fn write_to(writer: &mut dyn RecordWriter, record: &Record, line: &str) -> io::Result<()> {
+    writer.write_record(record, line.as_bytes())?;
+    if !background_flush_active() {
+        writer.flush()?;
+    }
+    Ok(())
 }
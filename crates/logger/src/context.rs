@@ -0,0 +1,64 @@
+use std::cell::RefCell;
+
+/// Trace and span ids carried by the current thread
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TraceContext {
+    pub(crate) trace_id: String,
+    pub(crate) span_id: String,
+}
+
+thread_local! {
+    /// Trace context of the innermost `with_trace_context` scope
+    static TRACE_CONTEXT: RefCell<Option<TraceContext>> = const { RefCell::new(None) };
+
+    /// Fields of the open spans on this thread, outermost first
+    static SPAN_FIELDS: RefCell<Vec<(String, String)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Restores the previous trace context when a scope ends (even on panic)
+struct Restore(Option<TraceContext>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        let previous = self.0.take();
+        TRACE_CONTEXT.with(|context| *context.borrow_mut() = previous);
+    }
+}
+
+/// Run `body` with `trace_id` and `span_id` attached to every log on this thread
+///
+/// The ids are rendered as `trace_id` and `span_id` fields. Scopes nest, and
+/// the outer ids are restored when `body` returns.
+pub fn with_trace_context<R>(trace_id: &str, span_id: &str, body: impl FnOnce() -> R) -> R {
+    let context = TraceContext {
+        trace_id: trace_id.to_string(),
+        span_id: span_id.to_string(),
+    };
+    let _restore = Restore(TRACE_CONTEXT.with(|current| current.replace(Some(context))));
+    body()
+}
+
+/// Trace context of the current thread, if inside a scope
+pub(crate) fn trace_context() -> Option<TraceContext> {
+    TRACE_CONTEXT.with(|context| context.borrow().clone())
+}
+
+/// Attach `fields` to every log on this thread, returning the previous count
+pub(crate) fn push_span_fields(fields: &[(String, String)]) -> usize {
+    SPAN_FIELDS.with(|current| {
+        let mut current = current.borrow_mut();
+        let depth = current.len();
+        current.extend(fields.iter().cloned());
+        depth
+    })
+}
+
+/// Detach the span fields pushed after `depth`
+pub(crate) fn pop_span_fields(depth: usize) {
+    SPAN_FIELDS.with(|current| current.borrow_mut().truncate(depth));
+}
+
+/// Fields of the open spans on this thread
+pub(crate) fn span_fields() -> Vec<(String, String)> {
+    SPAN_FIELDS.with(|current| current.borrow().clone())
+}
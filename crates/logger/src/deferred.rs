@@ -0,0 +1,87 @@
+use crate::config::write_line;
+use crate::level::Level;
+use crate::record::{OwnedRecord, Record};
+use crate::utils::lock;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+/// Most lines held while waiting for a trigger (older ones are discarded)
+pub const DEFERRED_CAPACITY: usize = 1024;
+
+/// Records held until a trigger-level record (or `dump`) releases them
+struct Deferred {
+    trigger: Level,
+    pending: VecDeque<(OwnedRecord, String)>,
+}
+
+/// Active deferred dump (None = lines are written immediately)
+static DEFERRED: Mutex<Option<Deferred>> = Mutex::new(None);
+
+/// Outcome of offering a line to the deferred buffer
+pub(crate) enum Deferral {
+    /// Deferring is off; write the line as usual
+    Inactive(String),
+    /// The line was buffered
+    Held,
+    /// The line triggered a dump; write these in order
+    Released(Vec<(OwnedRecord, String)>),
+}
+
+/// Hold log lines in memory until a record at `trigger` or above is logged
+///
+/// The trigger writes the held lines followed by itself, so a failure comes
+/// with the context leading up to it. Lines still held at exit are
+/// discarded. At most `DEFERRED_CAPACITY` lines are kept, dropping the oldest.
+pub fn set_deferred_dump(trigger: Level) {
+    let mut deferred = lock(&DEFERRED);
+    match deferred.as_mut() {
+        Some(deferred) => deferred.trigger = trigger,
+        None => {
+            *deferred = Some(Deferred {
+                trigger,
+                pending: VecDeque::new(),
+            })
+        }
+    }
+}
+
+/// Stop deferring and discard any held lines
+pub fn clear_deferred_dump() {
+    *lock(&DEFERRED) = None;
+}
+
+/// Write all held lines to the output now
+pub fn dump() {
+    let pending: Vec<_> = match lock(&DEFERRED).as_mut() {
+        Some(deferred) => deferred.pending.drain(..).collect(),
+        None => return,
+    };
+    write_all(pending);
+}
+
+/// Buffer `line` if deferring, or release the buffer if `record` triggers it
+pub(crate) fn defer(record: &Record, line: String) -> Deferral {
+    let mut deferred = lock(&DEFERRED);
+    let Some(deferred) = deferred.as_mut() else {
+        return Deferral::Inactive(line);
+    };
+
+    if deferred.pending.len() == DEFERRED_CAPACITY {
+        deferred.pending.pop_front();
+    }
+    deferred
+        .pending
+        .push_back((OwnedRecord::from(record), line));
+    if record.level() >= deferred.trigger {
+        Deferral::Released(deferred.pending.drain(..).collect())
+    } else {
+        Deferral::Held
+    }
+}
+
+/// Write released lines to the configured output
+pub(crate) fn write_all(pending: Vec<(OwnedRecord, String)>) {
+    for (record, line) in pending {
+        record.with_record(|record| write_line(record, &line, None));
+    }
+}
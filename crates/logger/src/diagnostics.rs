@@ -0,0 +1,199 @@
+use crate::config::{
+    fixed_timestamp, line_terminator, timestamp_settings, writer_name, TimestampFormat,
+    TimestampPosition,
+};
+use crate::filter::module_levels;
+use crate::format::{formatter, theme, ColorScope, Theme};
+use crate::level::{max_level, Level};
+use crate::loggers::log;
+use crate::record::Record;
+use crate::sampling::sampling_rules;
+use crate::sanitize::escape_settings;
+use crate::utils::short_type_name;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+/// Whether `init` has been called
+static INITIALIZED: AtomicBool = AtomicBool::new(false);
+
+/// Records emitted before `init`
+static EARLY_RECORDS: AtomicU64 = AtomicU64::new(0);
+
+/// Whether `init` warns about records emitted before it
+static WARN_ON_LATE_INIT: AtomicBool = AtomicBool::new(false);
+
+/// Snapshot of the logger's effective configuration
+#[derive(Debug, Clone, PartialEq)]
+pub struct EffectiveConfig {
+    /// Global minimum level
+    pub max_level: Level,
+    /// Per-target overrides as (prefix, level)
+    pub module_levels: Vec<(String, Level)>,
+    /// Name of the active formatter
+    pub format: String,
+    /// Active color theme, including whether color is enabled and its scope
+    pub theme: Theme,
+    /// Fixed timestamp, if set (None = current time)
+    pub fixed_timestamp: Option<String>,
+    /// How timestamps are rendered
+    pub timestamp_format: TimestampFormat,
+    /// Where timestamps are placed
+    pub timestamp_position: TimestampPosition,
+    /// Whether timestamps include milliseconds
+    pub timestamp_subsecond: bool,
+    /// Whether control characters are escaped
+    pub sanitize_messages: bool,
+    /// Whether newlines in messages are escaped
+    pub escape_newlines: bool,
+    /// Whether NUL bytes are escaped
+    pub escape_nul: bool,
+    /// Sampling rules as (prefix, level, ratio)
+    pub sampling_rules: Vec<(String, Level, f64)>,
+    /// Line terminator
+    pub line_terminator: String,
+    /// Type name of the active writer
+    pub writer: String,
+}
+
+impl fmt::Display for EffectiveConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "level={}", self.max_level)?;
+        if !self.module_levels.is_empty() {
+            let modules: Vec<String> = self
+                .module_levels
+                .iter()
+                .map(|(target, level)| format!("{target}={level}"))
+                .collect();
+            write!(f, " modules={}", modules.join(","))?;
+        }
+        write!(f, " format={} writer={}", self.format, self.writer)?;
+        let scope = match self.theme.scope {
+            ColorScope::LevelOnly => "level",
+            ColorScope::WholeLine => "line",
+            ColorScope::LevelAndMessage => "level+message",
+        };
+        // Color and scope are shown on their own; the rest is default or custom
+        let colors = Theme {
+            enabled: Theme::DEFAULT.enabled,
+            scope: Theme::DEFAULT.scope,
+            ..self.theme
+        };
+        let colors = if colors == Theme::DEFAULT {
+            "default"
+        } else {
+            "custom"
+        };
+        write!(
+            f,
+            " color={} scope={scope} theme={colors}",
+            on_off(self.theme.enabled)
+        )?;
+        match &self.fixed_timestamp {
+            Some(ts) => write!(f, " timestamp=fixed({ts})")?,
+            None => write!(f, " timestamp={:?}", self.timestamp_format)?,
+        }
+        write!(
+            f,
+            " timestamp_position={:?} subsecond={}",
+            self.timestamp_position,
+            on_off(self.timestamp_subsecond)
+        )?;
+        write!(
+            f,
+            " sanitize={} escape_newlines={} escape_nul={}",
+            on_off(self.sanitize_messages),
+            on_off(self.escape_newlines),
+            on_off(self.escape_nul)
+        )?;
+        if !self.sampling_rules.is_empty() {
+            let rules: Vec<String> = self
+                .sampling_rules
+                .iter()
+                .map(|(prefix, level, ratio)| format!("{prefix}={level}@{ratio}"))
+                .collect();
+            write!(f, " sampling={}", rules.join(","))?;
+        }
+        write!(f, " terminator={:?}", self.line_terminator)
+    }
+}
+
+/// `on` or `off`, for the summary line
+fn on_off(enabled: bool) -> &'static str {
+    if enabled {
+        "on"
+    } else {
+        "off"
+    }
+}
+
+/// Get a snapshot of the current logger configuration
+pub fn effective_config() -> EffectiveConfig {
+    let (timestamp_format, timestamp_position, timestamp_subsecond) = timestamp_settings();
+    let (sanitize_messages, escape_newlines, escape_nul) = escape_settings();
+    EffectiveConfig {
+        max_level: max_level(),
+        module_levels: module_levels(),
+        format: formatter().name().to_string(),
+        theme: theme(),
+        fixed_timestamp: fixed_timestamp(),
+        timestamp_format,
+        timestamp_position,
+        timestamp_subsecond,
+        sanitize_messages,
+        escape_newlines,
+        escape_nul,
+        sampling_rules: sampling_rules(),
+        line_terminator: line_terminator(),
+        writer: short_type_name(writer_name()),
+    }
+}
+
+/// Log a one-line summary of the configuration, e.g. for service startup
+///
+/// Emitted at info level (so suppressed when the level is above info) as
+/// `telemetry: level=info format=text output=StdoutWriter`.
+pub fn log_startup_info() {
+    let config = effective_config();
+    let mut message = format!("telemetry: level={}", config.max_level);
+    for (target, level) in &config.module_levels {
+        message.push_str(&format!(" {target}={level}"));
+    }
+    message.push_str(&format!(
+        " format={} output={}",
+        config.format, config.writer
+    ));
+    log(&Record::new(Level::Info, &message).with_target("rust_telemetry"));
+}
+
+/// Mark the logger as configured, e.g. at the end of the app's logging setup
+///
+/// Records logged earlier (say, by a library) went out with the default
+/// configuration; see `logged_before_init` and `set_warn_on_late_init`.
+pub fn init() {
+    if INITIALIZED.swap(true, Ordering::Relaxed) {
+        return;
+    }
+
+    let early = EARLY_RECORDS.load(Ordering::Relaxed);
+    if early > 0 && WARN_ON_LATE_INIT.load(Ordering::Relaxed) {
+        let message = format!("telemetry: {early} records were logged before init");
+        log(&Record::new(Level::Warn, &message).with_target("rust_telemetry"));
+    }
+}
+
+/// Make `init` log a warning if records were emitted before it
+pub fn set_warn_on_late_init(enabled: bool) {
+    WARN_ON_LATE_INIT.store(enabled, Ordering::Relaxed);
+}
+
+/// Number of records emitted before `init` was called
+pub fn logged_before_init() -> u64 {
+    EARLY_RECORDS.load(Ordering::Relaxed)
+}
+
+/// Count an emitted record if `init` has not been called yet
+pub(crate) fn note_emitted() {
+    if !INITIALIZED.load(Ordering::Relaxed) {
+        EARLY_RECORDS.fetch_add(1, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,145 @@
+use crate::stats::stats;
+use crate::utils::{lock, read, write};
+use std::fmt;
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+
+type FailureAlert = Arc<dyn Fn(&io::Error) + Send + Sync>;
+
+/// Whether failed writes are retried on stderr
+static STDERR_FALLBACK: AtomicBool = AtomicBool::new(false);
+
+/// Failed writes since the last successful one
+static CONSECUTIVE: AtomicU32 = AtomicU32::new(0);
+
+/// Callback fired after a number of consecutive failures
+static ALERT: RwLock<Option<(u32, FailureAlert)>> = RwLock::new(None);
+
+/// How a persistent write failure is escalated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WriteFailureMode {
+    /// Keep going; failures are only counted (default)
+    #[default]
+    Continue,
+    /// Panic on the logging thread
+    Panic,
+    /// Abort the process
+    Abort,
+    /// Invoke the callback set with `set_fatal_callback` (panics if none is set)
+    Fatal,
+}
+
+/// Escalation mode and the consecutive failures that trigger it
+static FAILURE_MODE: RwLock<(WriteFailureMode, u32)> = RwLock::new((WriteFailureMode::Continue, 0));
+
+/// Callback for `WriteFailureMode::Fatal`
+static FATAL: RwLock<Option<FailureAlert>> = RwLock::new(None);
+
+/// Escalate with `mode` once `threshold` consecutive writes have failed
+///
+/// For logs that must not be lost silently, e.g. audit trails.
+pub fn set_write_failure_mode(mode: WriteFailureMode, threshold: u32) {
+    *write(&FAILURE_MODE) = (mode, threshold);
+}
+
+/// Set the callback invoked in `WriteFailureMode::Fatal`
+pub fn set_fatal_callback(callback: Box<dyn Fn(&io::Error) + Send + Sync>) {
+    *write(&FATAL) = Some(Arc::from(callback));
+}
+
+/// Escalate a failure streak of `streak` according to the failure mode
+fn escalate(streak: u32, err: &io::Error) {
+    let (mode, threshold) = *read(&FAILURE_MODE);
+    if streak != threshold {
+        return;
+    }
+
+    match mode {
+        WriteFailureMode::Continue => {}
+        WriteFailureMode::Panic => panic!("log output failed {streak} times in a row: {err}"),
+        WriteFailureMode::Abort => {
+            internal_error(format_args!(
+                "log output failed {streak} times in a row: {err}"
+            ));
+            std::process::abort();
+        }
+        WriteFailureMode::Fatal => {
+            let fatal = read(&FATAL).clone();
+            match fatal {
+                Some(callback) => callback(err),
+                None => panic!("log output failed {streak} times in a row: {err}"),
+            }
+        }
+    }
+}
+
+/// Destination of the logger's own diagnostics (None = stderr)
+static INTERNAL_SINK: Mutex<Option<Box<dyn Write + Send>>> = Mutex::new(None);
+
+/// Send the logger's own error messages to `sink` instead of stderr
+///
+/// Covers diagnostics such as failed writes and panicking callbacks, not
+/// log lines (see `set_stderr_fallback` for those).
+pub fn set_internal_error_sink(sink: Box<dyn Write + Send>) {
+    *lock(&INTERNAL_SINK) = Some(sink);
+}
+
+/// Send the logger's own error messages back to stderr
+pub fn clear_internal_error_sink() {
+    *lock(&INTERNAL_SINK) = None;
+}
+
+/// Report a problem with the logger itself, prefixed with `rust-telemetry: `
+pub(crate) fn internal_error(message: fmt::Arguments) {
+    let mut sink = lock(&INTERNAL_SINK);
+    let _ = match sink.as_mut() {
+        Some(sink) => writeln!(sink, "rust-telemetry: {message}"),
+        None => writeln!(io::stderr(), "rust-telemetry: {message}"),
+    };
+}
+
+/// Write log lines to stderr when the configured output fails
+pub fn set_stderr_fallback(enabled: bool) {
+    STDERR_FALLBACK.store(enabled, Ordering::Relaxed);
+}
+
+/// Invoke `callback` once `threshold` consecutive writes have failed
+pub fn set_failure_alert(threshold: u32, callback: Box<dyn Fn(&io::Error) + Send + Sync>) {
+    let mut alert = write(&ALERT);
+    *alert = Some((threshold, Arc::from(callback)));
+}
+
+/// Remove the failure alert callback
+pub fn clear_failure_alert() {
+    let mut alert = write(&ALERT);
+    *alert = None;
+}
+
+/// Reset the consecutive failure streak after a successful write (or a new output)
+pub(crate) fn record_success() {
+    CONSECUTIVE.store(0, Ordering::Relaxed);
+}
+
+/// Handle a failed write of `line` according to the configured policy
+pub(crate) fn record_failure(line: &str, err: &io::Error) {
+    stats().record_write_failure();
+
+    if STDERR_FALLBACK.load(Ordering::Relaxed) {
+        eprint!("{line}");
+        stats().record_fallback_write();
+    }
+
+    let streak = CONSECUTIVE.fetch_add(1, Ordering::Relaxed) + 1;
+    if streak == 1 {
+        internal_error(format_args!("failed to write log: {err}"));
+    }
+    let alert = match read(&ALERT).as_ref() {
+        Some((threshold, callback)) if *threshold == streak => Some(Arc::clone(callback)),
+        _ => None,
+    };
+    if let Some(alert) = alert {
+        alert(err);
+    }
+    escalate(streak, err);
+}
@@ -0,0 +1,156 @@
+use crate::level::{max_level, set_max_level, Level, ParseLevelError};
+use crate::stats::stats;
+use crate::utils::{read, write};
+use std::fmt;
+use std::str::FromStr;
+use std::sync::RwLock;
+
+/// Per-target level overrides as (prefix, level)
+static MODULE_LEVELS: RwLock<Vec<(String, Level)>> = RwLock::new(Vec::new());
+
+/// Override the minimum level for a target or module path prefix
+pub fn set_module_level(prefix: &str, level: Level) {
+    let mut levels = write(&MODULE_LEVELS);
+    levels.retain(|(p, _)| p != prefix);
+    levels.push((prefix.to_string(), level));
+    drop(levels);
+    #[cfg(feature = "log-compat")]
+    crate::compat::sync_max_level();
+}
+
+/// Remove all per-target level overrides
+pub fn clear_module_levels() {
+    write(&MODULE_LEVELS).clear();
+    #[cfg(feature = "log-compat")]
+    crate::compat::sync_max_level();
+}
+
+/// Get the per-target overrides as (prefix, level)
+pub(crate) fn module_levels() -> Vec<(String, Level)> {
+    read(&MODULE_LEVELS).clone()
+}
+
+/// Most verbose level any target is written at: the global level or a lower override
+#[cfg(feature = "log-compat")]
+pub(crate) fn most_verbose_level() -> Level {
+    read(&MODULE_LEVELS)
+        .iter()
+        .map(|(_, level)| *level)
+        .fold(max_level(), Level::min)
+}
+
+/// Check whether `target` is `prefix` or a submodule of it
+pub(crate) fn matches(target: &str, prefix: &str) -> bool {
+    match target.strip_prefix(prefix) {
+        Some(rest) => rest.is_empty() || rest.starts_with("::"),
+        None => false,
+    }
+}
+
+/// Minimum level for a target (longest matching override, else the global level)
+pub(crate) fn level_for(target: Option<&str>) -> Level {
+    let Some(target) = target else {
+        return max_level();
+    };
+
+    read(&MODULE_LEVELS)
+        .iter()
+        .filter(|(prefix, _)| matches(target, prefix))
+        .max_by_key(|(prefix, _)| prefix.len())
+        .map(|(_, level)| *level)
+        .unwrap_or_else(max_level)
+}
+
+/// Check whether a record at `level` for `target` would be written
+///
+/// Unlike `log_enabled`, this honors the per-target overrides from
+/// `set_module_level` and `set_filter`, so it is the check to use when
+/// guarding expensive work in a module with its own level.
+pub fn enabled(target: &str, level: Level) -> bool {
+    level != Level::Off && level >= level_for(Some(target))
+}
+
+/// Filter check run by the macros before formatting (counts suppressed records)
+pub fn macro_enabled(level: Level, target: &str) -> bool {
+    let enabled = enabled(target, level);
+    if !enabled {
+        stats().record_suppressed_by_level();
+    }
+    enabled
+}
+
+/// A parsed filter expression: a default level plus per-target overrides
+///
+/// ```ignore
+/// let filter: LevelFilter = "warn,db=debug,http::client=error".parse()?;
+/// set_filter(&filter);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LevelFilter {
+    default: Option<Level>,
+    directives: Vec<(String, Level)>,
+}
+
+impl LevelFilter {
+    /// Default level for targets without an override
+    pub fn default_level(&self) -> Option<Level> {
+        self.default
+    }
+
+    /// Per-target overrides as (prefix, level), in order
+    pub fn directives(&self) -> &[(String, Level)] {
+        &self.directives
+    }
+}
+
+impl FromStr for LevelFilter {
+    type Err = ParseLevelError;
+
+    /// Parse comma-separated directives: `level` or `target=level`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut filter = LevelFilter::default();
+
+        for directive in s.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                Some((target, level)) => {
+                    let target = target.trim();
+                    if target.is_empty() {
+                        return Err(ParseLevelError::new(directive));
+                    }
+                    filter.directives.retain(|(t, _)| t != target);
+                    filter.directives.push((target.to_string(), level.parse()?));
+                }
+                None => filter.default = Some(directive.parse()?),
+            }
+        }
+
+        Ok(filter)
+    }
+}
+
+impl fmt::Display for LevelFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(level) = self.default {
+            parts.push(level.to_string());
+        }
+        for (target, level) in &self.directives {
+            parts.push(format!("{target}={level}"));
+        }
+        f.write_str(&parts.join(","))
+    }
+}
+
+/// Apply a filter, replacing the per-target overrides (and the global level if set)
+pub fn set_filter(filter: &LevelFilter) {
+    if let Some(level) = filter.default {
+        set_max_level(level);
+    }
+
+    let mut levels = write(&MODULE_LEVELS);
+    levels.clear();
+    levels.extend(filter.directives.iter().cloned());
+    drop(levels);
+    #[cfg(feature = "log-compat")]
+    crate::compat::sync_max_level();
+}
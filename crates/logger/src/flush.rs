@@ -0,0 +1,78 @@
+use crate::config::{flush_output, shutdown_output};
+use crate::utils::lock;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Condvar, Mutex, PoisonError};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// Current flush interval (None = background flushing stopped)
+static INTERVAL: Mutex<Option<Duration>> = Mutex::new(None);
+
+/// Wakes the flusher when the interval changes
+static WAKE: Condvar = Condvar::new();
+
+/// Handle of the background flusher thread
+static WORKER: Mutex<Option<JoinHandle<()>>> = Mutex::new(None);
+
+/// Whether per-line flushing is replaced by the background flusher
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Flush the output at most every `interval` from a background thread
+///
+/// While enabled, log calls no longer flush after every line, so buffered
+/// writers (e.g. `BufWriter`) batch their output.
+pub fn set_flush_interval(interval: Duration) {
+    *lock(&INTERVAL) = Some(interval);
+    ACTIVE.store(true, Ordering::Relaxed);
+    WAKE.notify_all();
+
+    let mut worker = lock(&WORKER);
+    if worker.is_none() {
+        *worker = Some(thread::spawn(run));
+    }
+}
+
+/// Stop the background flusher, wait for it to exit and flush once more
+pub fn clear_flush_interval() {
+    *lock(&INTERVAL) = None;
+    WAKE.notify_all();
+
+    if let Some(worker) = lock(&WORKER).take() {
+        let _ = worker.join();
+    }
+    ACTIVE.store(false, Ordering::Relaxed);
+    flush_output();
+}
+
+/// Flush everything and shut down background writers, for a clean exit
+///
+/// Stops the background flusher, then drains the output (e.g. a
+/// `NonBlockingWriter` queue) and joins its worker thread. Records logged
+/// meanwhile wait for the drain to finish; records logged afterwards are
+/// written directly. Returns whether everything was written within `timeout`.
+pub fn shutdown(timeout: Duration) -> bool {
+    clear_flush_interval();
+    shutdown_output(timeout)
+}
+
+/// Whether log calls should skip their own flush
+pub(crate) fn background_flush_active() -> bool {
+    ACTIVE.load(Ordering::Relaxed)
+}
+
+/// Flusher loop: flush on every timeout until the interval is cleared
+fn run() {
+    let mut interval = lock(&INTERVAL);
+    while let Some(period) = *interval {
+        let (guard, wait) = WAKE
+            .wait_timeout(interval, period)
+            .unwrap_or_else(PoisonError::into_inner);
+        interval = guard;
+
+        if wait.timed_out() && interval.is_some() {
+            drop(interval);
+            flush_output();
+            interval = lock(&INTERVAL);
+        }
+    }
+}
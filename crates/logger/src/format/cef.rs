@@ -0,0 +1,117 @@
+use crate::format::Formatter;
+use crate::level::Level;
+use crate::record::Record;
+use crate::utils::{read, write};
+use std::borrow::Cow;
+use std::fmt::Write;
+use std::sync::RwLock;
+
+/// Device vendor, product and version written in every CEF header
+static DEVICE: RwLock<(Cow<'static, str>, Cow<'static, str>, Cow<'static, str>)> = RwLock::new((
+    Cow::Borrowed("rust-telemetry"),
+    Cow::Borrowed("rust-telemetry"),
+    Cow::Borrowed(env!("CARGO_PKG_VERSION")),
+));
+
+/// Set the device vendor, product and version of the CEF header
+pub fn set_cef_device(vendor: &str, product: &str, version: &str) {
+    *write(&DEVICE) = (
+        Cow::Owned(vendor.to_string()),
+        Cow::Owned(product.to_string()),
+        Cow::Owned(version.to_string()),
+    );
+}
+
+/// ArcSight Common Event Format, for SIEMs
+///
+/// `time CEF:0|vendor|product|version|signatureId|name|severity|extension`,
+/// where the signature id is the record's `dedup_key` in hex, the name is
+/// the message and the extension holds `rt` (the record time in epoch
+/// milliseconds) followed by the fields as `key=value`.
+pub struct CefFormatter;
+
+impl CefFormatter {
+    /// CEF severity (0-10) for a level
+    pub fn severity(level: Level) -> u8 {
+        match level {
+            Level::Debug | Level::Off => 1,
+            Level::Info => 3,
+            Level::Warn => 6,
+            Level::Error => 9,
+        }
+    }
+}
+
+impl Formatter for CefFormatter {
+    fn format(&self, record: &Record, time: &str, out: &mut String) {
+        if !time.is_empty() {
+            let _ = write!(out, "{time} ");
+        }
+        out.push_str("CEF:0|");
+        {
+            let device = read(&DEVICE);
+            for part in [&device.0, &device.1, &device.2] {
+                push_header(out, part);
+                out.push('|');
+            }
+        }
+        let _ = write!(out, "{:016x}|", record.dedup_key());
+        push_header(out, record.message());
+        let _ = write!(out, "|{}|", Self::severity(record.level()));
+
+        let mut first = true;
+        if let Some(time) = record.time() {
+            let _ = write!(out, "rt={}", time.as_millis());
+            first = false;
+        }
+        for (key, value) in record.fields() {
+            if !first {
+                out.push(' ');
+            }
+            push_key(out, key);
+            out.push('=');
+            push_value(out, &value.to_string());
+            first = false;
+        }
+    }
+
+    fn name(&self) -> &str {
+        "cef"
+    }
+}
+
+/// Append a header field, escaping `\` and `|` (newlines become spaces)
+fn push_header(out: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '|' => out.push_str("\\|"),
+            '\n' | '\r' => out.push(' '),
+            c => out.push(c),
+        }
+    }
+}
+
+/// Append an extension key, replacing characters other than `A-Za-z0-9_`
+fn push_key(out: &mut String, key: &str) {
+    if key.is_empty() {
+        out.push('_');
+    }
+    out.extend(key.chars().map(|c| match c {
+        'a'..='z' | 'A'..='Z' | '0'..='9' | '_' => c,
+        _ => '_',
+    }));
+}
+
+/// Append an extension value, escaping `\`, `=` and line breaks
+fn push_value(out: &mut String, value: &str) {
+    for c in value.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '=' => out.push_str("\\="),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            c => out.push(c),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use crate::record::Record;
+use alloc::string::String;
+use core::fmt;
+
+/// Renders a record into a log line
+pub trait Formatter: Send + Sync {
+    /// Append the rendered line for `record` (without terminator) to `out`
+    ///
+    /// `time` is empty when no timestamp is available (e.g. without `std`).
+    fn format(&self, record: &Record, time: &str, out: &mut String);
+
+    /// Render `record` into any `fmt::Write` (without terminator)
+    fn format_to(&self, record: &Record, time: &str, out: &mut dyn fmt::Write) -> fmt::Result {
+        let mut line = String::new();
+        self.format(record, time, &mut line);
+        out.write_str(&line)
+    }
+
+    /// Name reported by `effective_config`
+    fn name(&self) -> &str {
+        "custom"
+    }
+}
+
+/// Built-in output formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// `time level message key=value` on a single line
+    Text,
+    /// Level and message on the first line, fields and location indented below
+    Pretty,
+    /// One-character colored level gutter, e.g. `│E│ time message`
+    Gutter,
+    /// Common Event Format for SIEMs, e.g. `CEF:0|vendor|product|...`
+    #[cfg(feature = "cef")]
+    Cef,
+}
@@ -0,0 +1,151 @@
+use crate::format::{
+    ColorScope, Format, Formatter, GutterFormatter, LayoutField, PrettyFormatter, TextFieldStyle,
+    TextFormatter, Theme,
+};
+use crate::level::Level;
+use crate::utils::{read, write};
+use std::borrow::Cow;
+use std::sync::{Arc, LazyLock, RwLock};
+
+/// Global formatter (defaults to text)
+static FORMATTER: LazyLock<RwLock<Arc<dyn Formatter>>> =
+    LazyLock::new(|| RwLock::new(Arc::new(TextFormatter)));
+
+/// Global color theme
+static THEME: RwLock<Theme> = RwLock::new(Theme::DEFAULT);
+
+/// Separator between the message and the first field (text format)
+static FIELD_SEPARATOR: RwLock<Cow<'static, str>> = RwLock::new(Cow::Borrowed(" "));
+
+/// Separator between a field's key and value (text format)
+static KV_SEPARATOR: RwLock<Cow<'static, str>> = RwLock::new(Cow::Borrowed("="));
+
+/// Field rendering of the text format
+static TEXT_FIELD_STYLE: RwLock<TextFieldStyle> = RwLock::new(TextFieldStyle::KeyValue);
+
+/// Column order of the text format (None = the default header layout)
+static FIELD_LAYOUT: RwLock<Option<Vec<LayoutField>>> = RwLock::new(None);
+
+/// Output names overriding `Level::as_str`, as (level, name)
+static LEVEL_NAMES: RwLock<Vec<(Level, String)>> = RwLock::new(Vec::new());
+
+/// Use one of the built-in formats
+pub fn set_format(format: Format) {
+    match format {
+        Format::Text => set_formatter(Box::new(TextFormatter)),
+        Format::Pretty => set_formatter(Box::new(PrettyFormatter)),
+        Format::Gutter => set_formatter(Box::new(GutterFormatter::new())),
+        #[cfg(feature = "cef")]
+        Format::Cef => set_formatter(Box::new(crate::format::CefFormatter)),
+    }
+}
+
+/// Use a custom formatter
+pub fn set_formatter(formatter: Box<dyn Formatter>) {
+    let mut f = write(&FORMATTER);
+    *f = Arc::from(formatter);
+}
+
+/// Get the current formatter
+pub(crate) fn formatter() -> Arc<dyn Formatter> {
+    Arc::clone(&read(&FORMATTER))
+}
+
+/// Set the color theme used by the built-in formatters
+pub fn set_theme(theme: Theme) {
+    *write(&THEME) = theme;
+}
+
+/// Enable or disable colored output (no escape codes at all when disabled)
+pub fn set_color_enabled(enabled: bool) {
+    write(&THEME).enabled = enabled;
+}
+
+/// Choose which part of each line takes the level color
+pub fn set_color_scope(scope: ColorScope) {
+    write(&THEME).scope = scope;
+}
+
+/// Get the active theme
+pub(crate) fn theme() -> Theme {
+    *read(&THEME)
+}
+
+/// Set the separator between the message and fields in the text format
+///
+/// Defaults to `" "`; e.g. `" | "` renders `msg | k=v`.
+pub fn set_field_separator(separator: &str) {
+    *write(&FIELD_SEPARATOR) = Cow::Owned(separator.to_string());
+}
+
+/// Set the separator between field keys and values in the text format (default `"="`)
+pub fn set_kv_separator(separator: &str) {
+    *write(&KV_SEPARATOR) = Cow::Owned(separator.to_string());
+}
+
+/// Render text-format fields as `key=value` pairs or a JSON object
+pub fn set_text_field_style(style: TextFieldStyle) {
+    *write(&TEXT_FIELD_STYLE) = style;
+}
+
+/// Get the text-format field style
+pub(crate) fn text_field_style() -> TextFieldStyle {
+    *read(&TEXT_FIELD_STYLE)
+}
+
+/// Set the column order of the text format
+///
+/// Columns are joined by a space and empty ones are skipped, so
+/// `&[Level, Timestamp, Message, Fields]` renders `info 12:34:56.789 message k=v`.
+/// Columns left out of `layout` are not written.
+pub fn set_field_layout(layout: &[LayoutField]) {
+    *write(&FIELD_LAYOUT) = Some(layout.to_vec());
+}
+
+/// Restore the default text layout
+pub fn clear_field_layout() {
+    *write(&FIELD_LAYOUT) = None;
+}
+
+/// Get the custom text layout, if set
+pub(crate) fn field_layout() -> Option<Vec<LayoutField>> {
+    read(&FIELD_LAYOUT).clone()
+}
+
+/// Run `f` with the field and key-value separators
+pub(crate) fn with_separators<R>(f: impl FnOnce(&str, &str) -> R) -> R {
+    f(&read(&FIELD_SEPARATOR), &read(&KV_SEPARATOR))
+}
+
+/// Override the level names written to the output
+///
+/// Levels missing from `names` keep their default label, and parsing still
+/// accepts the canonical names, e.g. `&[(Level::Warn, "WARNING")]`.
+pub fn set_level_names(names: &[(Level, &str)]) {
+    let mut levels = write(&LEVEL_NAMES);
+    levels.clear();
+    levels.extend(names.iter().map(|(level, name)| (*level, name.to_string())));
+}
+
+/// Restore the default level names
+pub fn clear_level_names() {
+    write(&LEVEL_NAMES).clear();
+}
+
+/// Name written to the output for `level`
+pub(crate) fn level_name(level: Level) -> Cow<'static, str> {
+    read(&LEVEL_NAMES)
+        .iter()
+        .find(|(l, _)| *l == level)
+        .map_or(Cow::Borrowed(level.as_str()), |(_, name)| {
+            Cow::Owned(name.clone())
+        })
+}
+
+/// Level whose custom output name is `name`
+pub(crate) fn level_from_name(name: &str) -> Option<Level> {
+    read(&LEVEL_NAMES)
+        .iter()
+        .find(|(_, n)| n == name)
+        .map(|(level, _)| *level)
+}
@@ -0,0 +1,67 @@
+use crate::format::header::{write_delta, write_meta};
+use crate::format::text::write_fields;
+use crate::format::{theme, Formatter};
+use crate::level::{Level, LEVEL_COUNT};
+use crate::record::Record;
+use alloc::string::String;
+use core::fmt::Write;
+
+/// Dense format with a one-character level gutter: `│E│ time message key=value`
+///
+/// Only the gutter is colored. The glyphs default to `D`, `I`, `W` and `E`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GutterFormatter {
+    glyphs: [char; LEVEL_COUNT],
+}
+
+impl GutterFormatter {
+    pub const fn new() -> Self {
+        Self {
+            glyphs: ['D', 'I', 'W', 'E'],
+        }
+    }
+
+    /// Set the glyph shown for `level`
+    pub fn glyph(mut self, level: Level, glyph: char) -> Self {
+        if let Some(slot) = self.glyphs.get_mut(level as usize) {
+            *slot = glyph;
+        }
+        self
+    }
+
+    /// Glyph shown for `level` (a space for `Off`)
+    fn glyph_for(&self, level: Level) -> char {
+        self.glyphs.get(level as usize).copied().unwrap_or(' ')
+    }
+}
+
+impl Default for GutterFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Formatter for GutterFormatter {
+    fn format(&self, record: &Record, time: &str, out: &mut String) {
+        let level = record.level();
+        let mut gutter = String::new();
+        let _ = write!(gutter, "│{}│", self.glyph_for(level));
+        theme().paint(out, level.color(), gutter);
+        if let Some(delta) = record.delta() {
+            write_delta(out, delta);
+        }
+        out.push(' ');
+        write_meta(record, time, out);
+        out.push_str(record.message());
+        #[cfg(feature = "std")]
+        crate::format::global::with_separators(|field_sep, kv_sep| {
+            write_fields(record, field_sep, kv_sep, out)
+        });
+        #[cfg(not(feature = "std"))]
+        write_fields(record, " ", "=", out);
+    }
+
+    fn name(&self) -> &str {
+        "gutter"
+    }
+}
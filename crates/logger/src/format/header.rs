@@ -0,0 +1,85 @@
+use crate::format::{theme, ColorScope};
+use crate::level::Level;
+use crate::record::Record;
+use alloc::string::String;
+use core::fmt::Write;
+use core::time::Duration;
+
+/// Write the common line header: `[time] [#seq] [[thread]] level [(+delta)] message`
+pub(crate) fn write_header(record: &Record, time: &str, out: &mut String) {
+    write_meta(record, time, out);
+    write_level(record, out);
+    out.push(' ');
+    write_message(record, out);
+}
+
+/// Write the level label (colored per the theme) and the delta, if any
+pub(crate) fn write_level(record: &Record, out: &mut String) {
+    let level = record.level();
+    let theme = theme();
+    match theme.scope {
+        ColorScope::WholeLine => out.push_str(level_name(level).as_ref()),
+        _ => theme.paint(out, level.color(), level_name(level)),
+    }
+    if let Some(delta) = record.delta() {
+        write_delta(out, delta);
+    }
+}
+
+/// Write the message (colored when the scope is `LevelAndMessage`)
+pub(crate) fn write_message(record: &Record, out: &mut String) {
+    let theme = theme();
+    match theme.scope {
+        ColorScope::LevelAndMessage => theme.paint(out, record.level().color(), record.message()),
+        _ => out.push_str(record.message()),
+    }
+}
+
+/// Write the time, sequence number and thread, each followed by a space
+pub(crate) fn write_meta(record: &Record, time: &str, out: &mut String) {
+    if !time.is_empty() {
+        let _ = write!(out, "{time} ");
+    }
+    if let Some(seq) = record.seq() {
+        let _ = write!(out, "#{seq} ");
+    }
+    if let Some(thread) = record.thread() {
+        let _ = write!(out, "[{thread}] ");
+    }
+}
+
+/// Color the first line written since `start` when the scope is `WholeLine`
+pub(crate) fn paint_line(record: &Record, start: usize, out: &mut String) {
+    let theme = theme();
+    if theme.scope != ColorScope::WholeLine {
+        return;
+    }
+    let end = out[start..].find('\n').map_or(out.len(), |i| start + i);
+    let text = out.split_off(start);
+    theme.paint(out, record.level().color(), &text[..end - start]);
+    out.push_str(&text[end - start..]);
+}
+
+/// Write a delta like ` (+3.2ms)`
+pub(crate) fn write_delta(out: &mut String, delta: Duration) {
+    let millis = delta.as_secs_f64() * 1000.0;
+    let _ = if delta.is_zero() {
+        write!(out, " (+0ms)")
+    } else if millis < 1000.0 {
+        write!(out, " (+{millis:.1}ms)")
+    } else {
+        write!(out, " (+{:.2}s)", delta.as_secs_f64())
+    };
+}
+
+/// Name written for `level` (custom names need `std`)
+#[cfg(feature = "std")]
+fn level_name(level: Level) -> alloc::borrow::Cow<'static, str> {
+    crate::format::global::level_name(level)
+}
+
+/// Name written for `level` (custom names need `std`)
+#[cfg(not(feature = "std"))]
+fn level_name(level: Level) -> &'static str {
+    level.as_str()
+}
@@ -0,0 +1,29 @@
+#[cfg(feature = "cef")]
+mod cef;
+mod formatter;
+#[cfg(feature = "std")]
+mod global;
+mod gutter;
+mod header;
+mod pretty;
+pub(crate) mod safe;
+mod text;
+mod theme;
+
+#[cfg(feature = "cef")]
+pub use cef::{set_cef_device, CefFormatter};
+pub use formatter::{Format, Formatter};
+#[cfg(feature = "std")]
+pub use global::{
+    clear_field_layout, clear_level_names, set_color_enabled, set_color_scope, set_field_layout,
+    set_field_separator, set_format, set_formatter, set_kv_separator, set_level_names,
+    set_text_field_style, set_theme,
+};
+#[cfg(feature = "std")]
+pub(crate) use global::{formatter, level_from_name};
+pub use gutter::GutterFormatter;
+pub use pretty::PrettyFormatter;
+#[cfg(feature = "std")]
+pub(crate) use safe::PanicSafe;
+pub use text::{LayoutField, TextFieldStyle, TextFormatter};
+pub use theme::{theme, ColorScope, Theme};
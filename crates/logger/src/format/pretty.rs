@@ -0,0 +1,47 @@
+use crate::format::header::{paint_line, write_header};
+use crate::format::{theme, Formatter};
+use crate::record::Record;
+use alloc::string::String;
+use core::fmt::Write;
+
+/// Color applied to field keys when the theme sets none
+const KEY_COLOR: &str = "\x1b[1m";
+
+/// Multi-line format for development
+///
+/// The level and message go on the first line; each field and the source
+/// location follow on their own indented lines. Lines inside a record are
+/// always separated by `\n`, regardless of the configured terminator.
+pub struct PrettyFormatter;
+
+impl Formatter for PrettyFormatter {
+    fn format(&self, record: &Record, time: &str, out: &mut String) {
+        let theme = theme();
+        let key_color = match theme.field_key {
+            "" => KEY_COLOR,
+            color => color,
+        };
+        let start = out.len();
+        write_header(record, time, out);
+        paint_line(record, start, out);
+        for (key, value) in record.fields() {
+            out.push_str("\n    ");
+            if key.is_empty() {
+                let _ = write!(out, "{value}");
+                continue;
+            }
+            theme.paint(out, key_color, key);
+            out.push_str(": ");
+            theme.paint(out, theme.field_value, value);
+        }
+        if let (Some(file), Some(line)) = (record.file(), record.line()) {
+            out.push_str("\n    ");
+            theme.paint(out, key_color, "at");
+            let _ = write!(out, " {file}:{line}");
+        }
+    }
+
+    fn name(&self) -> &str {
+        "pretty"
+    }
+}
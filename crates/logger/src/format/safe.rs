@@ -0,0 +1,30 @@
+use alloc::string::String;
+use core::fmt;
+
+/// Text substituted for a value whose `Display` impl panicked
+#[cfg(feature = "std")]
+pub(crate) const PANIC_PLACEHOLDER: &str = "<panic while formatting>";
+
+/// Format `args`, yielding a placeholder if a user `Display` impl panics
+pub fn format_safe(args: fmt::Arguments) -> String {
+    #[cfg(feature = "std")]
+    {
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| alloc::fmt::format(args)))
+            .unwrap_or_else(|_| String::from(PANIC_PLACEHOLDER))
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        alloc::fmt::format(args)
+    }
+}
+
+/// `Display` wrapper that renders a placeholder if the inner value panics
+#[cfg(feature = "std")]
+pub(crate) struct PanicSafe<'a>(pub &'a dyn fmt::Display);
+
+#[cfg(feature = "std")]
+impl fmt::Display for PanicSafe<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&format_safe(format_args!("{}", self.0)))
+    }
+}
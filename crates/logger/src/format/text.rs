@@ -0,0 +1,153 @@
+use crate::format::header::{paint_line, write_header};
+#[cfg(feature = "std")]
+use crate::format::header::{write_level, write_message, write_meta};
+use crate::format::{theme, ColorScope, Formatter};
+use crate::record::Record;
+use alloc::string::String;
+#[cfg(feature = "std")]
+use core::fmt::Write;
+
+/// How the text format renders structured fields
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextFieldStyle {
+    /// `key=value` pairs (default)
+    #[default]
+    KeyValue,
+    /// A compact JSON object, e.g. `{"status":200,"path":"/"}`
+    Json,
+}
+
+/// A column of the text format, for `set_field_layout`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutField {
+    /// Timestamp, plus the sequence number and thread when enabled
+    Timestamp,
+    /// Level label (and delta when enabled)
+    Level,
+    /// Target or module path
+    Module,
+    /// Source location as `file:line`
+    Location,
+    /// Message text
+    Message,
+    /// Structured fields
+    Fields,
+}
+
+/// Single-line format: `time level message key=value ...`
+///
+/// The separators can be changed with `set_field_separator` and
+/// `set_kv_separator`, and fields can be rendered as JSON with
+/// `set_text_field_style`.
+pub struct TextFormatter;
+
+impl Formatter for TextFormatter {
+    fn format(&self, record: &Record, time: &str, out: &mut String) {
+        let start = out.len();
+        #[cfg(feature = "std")]
+        if let Some(layout) = crate::format::global::field_layout() {
+            write_layout(record, time, &layout, out);
+            paint_line(record, start, out);
+            return;
+        }
+        write_header(record, time, out);
+        #[cfg(feature = "std")]
+        crate::format::global::with_separators(|field_sep, kv_sep| {
+            write_styled_fields(record, field_sep, kv_sep, out)
+        });
+        #[cfg(not(feature = "std"))]
+        write_fields(record, " ", "=", out);
+        paint_line(record, start, out);
+    }
+
+    fn name(&self) -> &str {
+        "text"
+    }
+}
+
+/// Write `record`'s fields after the message (bare values for empty keys)
+///
+/// Keys and values take the theme's field colors, unless the whole line is
+/// already colored.
+pub(crate) fn write_fields(record: &Record, field_sep: &str, kv_sep: &str, out: &mut String) {
+    let mut theme = theme();
+    if theme.scope == ColorScope::WholeLine {
+        theme.enabled = false;
+    }
+    for (index, (key, value)) in record.fields().iter().enumerate() {
+        out.push_str(if index == 0 { field_sep } else { " " });
+        if !key.is_empty() {
+            theme.paint(out, theme.field_key, key);
+            out.push_str(kv_sep);
+        }
+        theme.paint(out, theme.field_value, value);
+    }
+}
+
+/// Write `record`'s fields in the configured `TextFieldStyle`
+#[cfg(feature = "std")]
+fn write_styled_fields(record: &Record, field_sep: &str, kv_sep: &str, out: &mut String) {
+    match crate::format::global::text_field_style() {
+        TextFieldStyle::KeyValue => write_fields(record, field_sep, kv_sep, out),
+        TextFieldStyle::Json => write_json_fields(record, field_sep, out),
+    }
+}
+
+/// Write the columns of `layout` in order, separated by spaces (empty ones skipped)
+#[cfg(feature = "std")]
+fn write_layout(record: &Record, time: &str, layout: &[LayoutField], out: &mut String) {
+    let mut first = true;
+    for column in layout {
+        let mut part = String::new();
+        match column {
+            LayoutField::Timestamp => {
+                write_meta(record, time, &mut part);
+                part.truncate(part.trim_end().len());
+            }
+            LayoutField::Level => write_level(record, &mut part),
+            LayoutField::Module => part.push_str(record.target().unwrap_or_default()),
+            LayoutField::Location => {
+                if let (Some(file), Some(line)) = (record.file(), record.line()) {
+                    let _ = write!(part, "{file}:{line}");
+                }
+            }
+            LayoutField::Message => write_message(record, &mut part),
+            LayoutField::Fields => crate::format::global::with_separators(|_, kv_sep| {
+                write_styled_fields(record, "", kv_sep, &mut part)
+            }),
+        }
+        if part.is_empty() {
+            continue;
+        }
+        if !first {
+            out.push(' ');
+        }
+        out.push_str(&part);
+        first = false;
+    }
+}
+
+/// Write `record`'s fields as a JSON object after the message
+#[cfg(feature = "std")]
+fn write_json_fields(record: &Record, field_sep: &str, out: &mut String) {
+    use crate::utils::{json_scalar, write_json_string};
+
+    if record.fields().is_empty() {
+        return;
+    }
+    out.push_str(field_sep);
+    out.push('{');
+    for (index, (key, value)) in record.fields().iter().enumerate() {
+        if index > 0 {
+            out.push(',');
+        }
+        write_json_string(out, key);
+        out.push(':');
+        if crate::optional::is_null(*value) {
+            out.push_str("null");
+        } else {
+            json_scalar(out, &value.to_string());
+        }
+    }
+    out.push('}');
+}
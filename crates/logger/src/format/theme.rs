@@ -0,0 +1,68 @@
+use alloc::string::String;
+use core::fmt::{Display, Write};
+
+/// Which part of a line takes the level color
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorScope {
+    /// Only the level label (default)
+    #[default]
+    LevelOnly,
+    /// The whole first line, from the timestamp to the last field
+    WholeLine,
+    /// The level label and the message
+    LevelAndMessage,
+}
+
+/// Color settings used by the built-in formatters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    /// Whether any escape codes are written at all
+    pub enabled: bool,
+    /// Sequence written after each colored span
+    pub reset: &'static str,
+    /// Which part of a line is colored
+    pub scope: ColorScope,
+    /// Color of structured field keys (empty = the format's default: bold in
+    /// pretty, plain otherwise)
+    pub field_key: &'static str,
+    /// Color of structured field values (empty = uncolored)
+    pub field_value: &'static str,
+}
+
+impl Theme {
+    /// ANSI colors with the standard reset
+    pub const DEFAULT: Theme = Theme {
+        enabled: true,
+        reset: "\x1b[0m",
+        scope: ColorScope::LevelOnly,
+        field_key: "",
+        field_value: "",
+    };
+
+    /// Write `text` wrapped in `color` and the reset (or plain if disabled)
+    pub fn paint(&self, out: &mut String, color: &str, text: impl Display) {
+        if self.enabled && !color.is_empty() {
+            let _ = write!(out, "{color}{text}{}", self.reset);
+        } else {
+            let _ = write!(out, "{text}");
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::DEFAULT
+    }
+}
+
+/// Get the active theme
+#[cfg(feature = "std")]
+pub fn theme() -> Theme {
+    crate::format::global::theme()
+}
+
+/// Get the active theme
+#[cfg(not(feature = "std"))]
+pub fn theme() -> Theme {
+    Theme::DEFAULT
+}
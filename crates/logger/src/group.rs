@@ -0,0 +1,65 @@
+use crate::level::Level;
+use crate::loggers::log;
+use crate::record::Record;
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Width of one level of group indentation
+const INDENT: &str = "  ";
+
+thread_local! {
+    /// Labels of the open groups on this thread, outermost first
+    static GROUPS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Whether entering and leaving a group logs its label
+static LOG_GROUPS: AtomicBool = AtomicBool::new(false);
+
+/// Log the group label at info level when a group is entered and left
+pub fn set_log_groups(enabled: bool) {
+    LOG_GROUPS.store(enabled, Ordering::Relaxed);
+}
+
+/// Open a group: messages logged on this thread are indented one more level
+pub fn push_group(label: &str) {
+    if LOG_GROUPS.load(Ordering::Relaxed) {
+        log(&Record::new(Level::Info, label));
+    }
+    GROUPS.with(|groups| groups.borrow_mut().push(label.to_string()));
+}
+
+/// Close the innermost group opened on this thread (no-op if none is open)
+pub fn pop_group() {
+    let Some(label) = GROUPS.with(|groups| groups.borrow_mut().pop()) else {
+        return;
+    };
+    if LOG_GROUPS.load(Ordering::Relaxed) {
+        log(&Record::new(Level::Info, &format!("{label} done")));
+    }
+}
+
+/// Closes a group when a scope ends (even on panic)
+struct Close;
+
+impl Drop for Close {
+    fn drop(&mut self) {
+        pop_group();
+    }
+}
+
+/// Run `body` inside a group labeled `label`
+pub fn with_group<R>(label: &str, body: impl FnOnce() -> R) -> R {
+    push_group(label);
+    let _close = Close;
+    body()
+}
+
+/// Indent `message` by the current thread's group depth
+pub(crate) fn indent(message: Cow<'_, str>) -> Cow<'_, str> {
+    let depth = GROUPS.with(|groups| groups.borrow().len());
+    if depth == 0 {
+        return message;
+    }
+    Cow::Owned(format!("{}{message}", INDENT.repeat(depth)))
+}
@@ -0,0 +1,39 @@
+use crate::failures::internal_error;
+use crate::record::Record;
+use crate::utils::{read, write};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+
+type RecordHook = Arc<dyn Fn(&Record) + Send + Sync>;
+
+/// Global callback invoked for every emitted record
+static ON_RECORD: RwLock<Option<RecordHook>> = RwLock::new(None);
+
+/// Whether a panicking callback has already been reported
+static PANIC_REPORTED: AtomicBool = AtomicBool::new(false);
+
+/// Set a callback invoked for every emitted record (e.g. to update metrics)
+pub fn set_on_record(hook: Box<dyn Fn(&Record) + Send + Sync>) {
+    let mut h = write(&ON_RECORD);
+    *h = Some(Arc::from(hook));
+}
+
+/// Remove the record callback
+pub fn clear_on_record() {
+    let mut h = write(&ON_RECORD);
+    *h = None;
+}
+
+/// Invoke the record callback, if any, without holding any logger lock
+pub(crate) fn on_record(record: &Record) {
+    let hook = match read(&ON_RECORD).as_ref() {
+        Some(hook) => Arc::clone(hook),
+        None => return,
+    };
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| hook(record)));
+    if result.is_err() && !PANIC_REPORTED.swap(true, Ordering::Relaxed) {
+        internal_error(format_args!("on_record callback panicked"));
+    }
+}
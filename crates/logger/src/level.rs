@@ -0,0 +1,191 @@
+use alloc::string::{String, ToString};
+use core::fmt;
+use core::str::FromStr;
+use core::sync::atomic::{AtomicU8, Ordering};
+
+/// Severity of a log record
+///
+/// `Off` is only meaningful as a filter level (`set_max_level(Level::Off)`
+/// disables all output); records at `Off` are never written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    Debug,
+    Info,
+    Warn,
+    Error,
+    Off,
+}
+
+/// Number of loggable levels (every level except `Off`)
+pub const LEVEL_COUNT: usize = 4;
+
+/// Compile-time minimum level for the logging macros
+///
+/// Set with the `max_level_info`, `max_level_warn`, `max_level_error` or
+/// `max_level_off` features; macro calls below it compile to nothing.
+pub const STATIC_MAX_LEVEL: Level = if cfg!(feature = "max_level_off") {
+    Level::Off
+} else if cfg!(feature = "max_level_error") {
+    Level::Error
+} else if cfg!(feature = "max_level_warn") {
+    Level::Warn
+} else if cfg!(feature = "max_level_info") {
+    Level::Info
+} else {
+    Level::Debug
+};
+
+/// Global minimum level (records below it are dropped)
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(Level::Debug as u8);
+
+/// Set the minimum level that will be written
+pub fn set_max_level(level: Level) {
+    MAX_LEVEL.store(level as u8, Ordering::Relaxed);
+    #[cfg(feature = "log-compat")]
+    crate::compat::sync_max_level();
+}
+
+/// Run `body` with the minimum level set to `level`, restoring the previous one afterwards
+///
+/// The previous level is restored even if `body` panics. The level is global,
+/// so other threads see the change while `body` runs, and overlapping scopes
+/// on different threads may restore each other's levels out of order.
+pub fn with_level<R>(level: Level, body: impl FnOnce() -> R) -> R {
+    let previous = Level::from_u8(MAX_LEVEL.swap(level as u8, Ordering::Relaxed));
+    #[cfg(feature = "log-compat")]
+    crate::compat::sync_max_level();
+    let _restore = RestoreLevel(previous);
+    body()
+}
+
+/// Puts back the previous level when a `with_level` scope ends
+struct RestoreLevel(Level);
+
+impl Drop for RestoreLevel {
+    fn drop(&mut self) {
+        set_max_level(self.0);
+    }
+}
+
+/// Get the minimum level that will be written
+pub fn max_level() -> Level {
+    Level::from_u8(MAX_LEVEL.load(Ordering::Relaxed))
+}
+
+/// Check whether records at `level` pass the level filter
+pub fn log_enabled(level: Level) -> bool {
+    level != Level::Off && level >= max_level()
+}
+
+impl Level {
+    /// All loggable levels from least to most severe (excludes `Off`)
+    pub const fn all() -> &'static [Level; LEVEL_COUNT] {
+        &[Level::Debug, Level::Info, Level::Warn, Level::Error]
+    }
+
+    /// Convert from the numeric representation (`level as u8`)
+    pub(crate) fn from_u8(value: u8) -> Level {
+        match value {
+            0 => Level::Debug,
+            1 => Level::Info,
+            2 => Level::Warn,
+            3 => Level::Error,
+            _ => Level::Off,
+        }
+    }
+
+    /// Parse a label produced by `as_str` or set with `set_level_names`
+    #[cfg(feature = "std")]
+    pub(crate) fn from_label(label: &str) -> Option<Level> {
+        if let Some(level) = crate::format::level_from_name(label) {
+            return Some(level);
+        }
+        Level::all()
+            .iter()
+            .copied()
+            .find(|level| level.as_str() == label)
+    }
+
+    /// Label written to the output
+    ///
+    /// This and `color` are the only definitions of the labels and colors;
+    /// formatters and parsers derive from them.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Level::Debug => "debg",
+            Level::Info => "info",
+            Level::Warn => "warn",
+            Level::Error => "errr",
+            Level::Off => "off",
+        }
+    }
+
+    /// ANSI color code for the label
+    pub fn color(&self) -> &'static str {
+        match self {
+            Level::Debug => "\x1b[36m",
+            Level::Info => "\x1b[32m",
+            Level::Warn => "\x1b[33m",
+            Level::Error => "\x1b[31m",
+            Level::Off => "",
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Level::Debug => "debug",
+            Level::Info => "info",
+            Level::Warn => "warn",
+            Level::Error => "error",
+            Level::Off => "off",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Error returned when parsing a level or filter fails
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseLevelError {
+    input: String,
+}
+
+impl ParseLevelError {
+    pub(crate) fn new(input: &str) -> Self {
+        Self {
+            input: input.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ParseLevelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid log level: {:?}", self.input)
+    }
+}
+
+impl core::error::Error for ParseLevelError {}
+
+impl FromStr for Level {
+    type Err = ParseLevelError;
+
+    /// Parse a level name (case-insensitive, e.g. `"info"`, `"WARN"`)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim();
+        let is = |candidate: &str| name.eq_ignore_ascii_case(candidate);
+        if is("debug") {
+            Ok(Level::Debug)
+        } else if is("info") {
+            Ok(Level::Info)
+        } else if is("warn") || is("warning") {
+            Ok(Level::Warn)
+        } else if is("error") {
+            Ok(Level::Error)
+        } else if is("off") {
+            Ok(Level::Off)
+        } else {
+            Err(ParseLevelError::new(s))
+        }
+    }
+}
@@ -1,14 +1,188 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
+mod adaptive;
+#[cfg(feature = "std")]
+mod audit;
+#[cfg(feature = "std")]
+mod bench;
+#[cfg(feature = "std")]
+mod clock;
+#[cfg(feature = "log-compat")]
+mod compat;
+#[cfg(feature = "std")]
 mod config;
+#[cfg(feature = "std")]
+mod context;
+#[cfg(feature = "std")]
+mod deferred;
+#[cfg(feature = "std")]
+mod diagnostics;
+#[cfg(feature = "std")]
+mod failures;
+#[cfg(feature = "std")]
+mod filter;
+#[cfg(feature = "std")]
+mod flush;
+pub mod format;
+#[cfg(feature = "std")]
+mod group;
+#[cfg(feature = "std")]
+mod hooks;
+mod level;
+#[cfg(feature = "std")]
 mod loggers;
+mod macros;
+#[cfg(feature = "std")]
+mod optional;
+mod record;
+#[cfg(feature = "std")]
+mod record_id;
+#[cfg(feature = "std")]
+mod sampling;
+#[cfg(feature = "std")]
+mod sanitize;
+#[cfg(not(feature = "std"))]
+mod sink;
+#[cfg(feature = "std")]
+mod span;
+#[cfg(feature = "std")]
+mod stats;
+#[cfg(feature = "std")]
+mod subscribe;
+#[cfg(feature = "std")]
+mod thread;
+#[cfg(feature = "std")]
+mod timer;
+#[cfg(feature = "std")]
 mod utils;
+#[cfg(feature = "std")]
 pub mod writers;
 
-pub use config::clear_output;
-pub use config::clear_timestamp;
-pub use config::set_output;
-pub use config::set_timestamp;
-pub use loggers::debug;
-pub use loggers::error;
-pub use loggers::info;
-pub use loggers::warn;
-pub use writers::{FileWriter, MemoryWriter, StdoutWriter};
+pub use format::{theme, ColorScope, Format, Formatter, LayoutField, TextFieldStyle, Theme};
+pub use level::log_enabled;
+pub use level::max_level;
+pub use level::set_max_level;
+pub use level::with_level;
+pub use level::{Level, ParseLevelError, LEVEL_COUNT, STATIC_MAX_LEVEL};
+pub use record::{Field, OwnedRecord, Record};
+#[cfg(not(feature = "std"))]
+pub use sink::{enabled, log, set_sink, SetSinkError, Sink};
+
+#[cfg(feature = "std")]
+pub use {
+    adaptive::set_adaptive_level,
+    audit::{audit, clear_audit_output, set_audit_output},
+    bench::reset_for_bench,
+    clock::clear_clock,
+    clock::set_clock,
+    clock::set_fixed_time,
+    clock::{clear_coarse_time, set_coarse_time},
+    clock::{Clock, MockClock, SystemClock},
+    config::bytes_written,
+    config::clear_output,
+    config::clear_output_local,
+    config::clear_timestamp,
+    config::set_compact_timestamps,
+    config::set_line_terminator,
+    config::set_output,
+    config::set_output_local,
+    config::set_timestamp,
+    config::set_timestamp_format,
+    config::set_timestamp_levels,
+    config::set_timestamp_position,
+    config::set_timestamp_subsecond,
+    config::swap_output,
+    config::with_captured_output,
+    config::with_output,
+    config::TimestampFormat,
+    config::TimestampPosition,
+    context::with_trace_context,
+    deferred::{clear_deferred_dump, dump, set_deferred_dump, DEFERRED_CAPACITY},
+    diagnostics::{effective_config, log_startup_info, EffectiveConfig},
+    diagnostics::{init, logged_before_init, set_warn_on_late_init},
+    failures::clear_failure_alert,
+    failures::set_failure_alert,
+    failures::set_stderr_fallback,
+    failures::{clear_internal_error_sink, set_internal_error_sink},
+    failures::{set_fatal_callback, set_write_failure_mode, WriteFailureMode},
+    filter::clear_module_levels,
+    filter::enabled,
+    filter::set_filter,
+    filter::set_module_level,
+    filter::LevelFilter,
+    flush::clear_flush_interval,
+    flush::set_flush_interval,
+    flush::shutdown,
+    format::{
+        clear_field_layout, clear_level_names, set_color_enabled, set_color_scope,
+        set_field_layout, set_field_separator, set_format, set_formatter, set_kv_separator,
+        set_level_names, set_text_field_style, set_theme,
+    },
+    group::{pop_group, push_group, set_log_groups, with_group},
+    hooks::clear_on_record,
+    hooks::set_on_record,
+    loggers::debug,
+    loggers::error,
+    loggers::info,
+    loggers::log,
+    loggers::log_at_caller,
+    loggers::log_bytes,
+    loggers::log_kv,
+    loggers::log_lazy,
+    loggers::log_to,
+    loggers::set_dedup_location,
+    loggers::set_max_fields,
+    loggers::set_show_delta,
+    loggers::set_show_seq,
+    loggers::set_skip_empty,
+    loggers::warn,
+    loggers::write_record,
+    loggers::{log_error, ErrorChain},
+    optional::{set_none_rendering, NoneRendering, OptionField},
+    record_id::{set_show_record_id, RECORD_ID_LEN},
+    sampling::set_sampling_rules,
+    sanitize::set_escape_newlines,
+    sanitize::set_escape_nul,
+    sanitize::set_sanitize_messages,
+    sanitize::set_trim_message,
+    span::Span,
+    stats::max_observed_level,
+    stats::reset_max_observed_level,
+    stats::track_max_observed_level,
+    stats::{stats, Stats},
+    subscribe::{subscribe, SUBSCRIBER_CAPACITY},
+    thread::{set_show_thread, set_thread_id_format, ThreadDisplay, ThreadIdFormat},
+    timer::Timer,
+    writers::{set_oversized_policy, OversizedPolicy},
+    writers::{
+        BatchWriter, ChannelWriter, FileWriter, FmtWriter, LevelShardedFileWriter, LeveledWriter,
+        MemoryWriter, NonBlockingWriter, NullWriter, OverflowPolicy, PrefixWriter, RecordWriter,
+        RingBufferWriter, RingEntry, RollInterval, RollingFileWriter, StdoutWriter, TeeWriter,
+        TestWriter, UdpWriter,
+    },
+};
+
+#[cfg(feature = "log-compat")]
+pub use compat::init_log_compat;
+#[cfg(feature = "cef")]
+pub use format::{set_cef_device, CefFormatter};
+#[cfg(all(feature = "journald", unix))]
+pub use writers::JournaldWriter;
+#[cfg(all(feature = "android", target_os = "android"))]
+pub use writers::LogcatWriter;
+#[cfg(feature = "otel")]
+pub use writers::OtlpWriter;
+#[cfg(all(feature = "apple", target_vendor = "apple"))]
+pub use writers::{OsLogPrivacy, OsLogWriter};
+
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "std")]
+    pub use crate::filter::macro_enabled;
+    pub use crate::format::safe::format_safe;
+    #[cfg(not(feature = "std"))]
+    pub use crate::sink::macro_enabled;
+}
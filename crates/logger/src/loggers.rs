@@ -1,21 +1,331 @@
+use crate::adaptive::sheds;
 use crate::config::write_log;
+use crate::context::{span_fields, trace_context};
+use crate::diagnostics::note_emitted;
+use crate::filter::level_for;
+use crate::format::safe::format_safe;
+use crate::format::PanicSafe;
+use crate::group::indent;
+use crate::hooks::on_record;
+use crate::level::{log_enabled, Level};
+use crate::optional::{is_none, none_rendering, null_field, NoneRendering};
+use crate::record::{Field, Record};
+use crate::record_id::{record_id, show_record_id};
+use crate::sampling::sampled;
+use crate::sanitize::{sanitize_message, Sanitized};
+use crate::stats::{observe_level, stats};
+use crate::subscribe::publish;
+use crate::thread::thread_label;
+use crate::utils::escape_bytes;
+use crate::writers::RecordWriter;
+use std::cell::{Cell, RefCell};
+use std::error::Error;
+use std::fmt;
+use std::panic::Location;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+/// Per-process record counter
+static SEQ: AtomicU64 = AtomicU64::new(0);
+
+/// Whether records carry a sequence number
+static SHOW_SEQ: AtomicBool = AtomicBool::new(false);
+
+/// Emit a monotonically increasing sequence number next to the timestamp
+pub fn set_show_seq(enabled: bool) {
+    SHOW_SEQ.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether records carry the time since the previous log on the same thread
+static SHOW_DELTA: AtomicBool = AtomicBool::new(false);
+
+/// Whether records with a blank message and no fields are dropped
+static SKIP_EMPTY: AtomicBool = AtomicBool::new(false);
+
+/// Drop records whose message is empty after trimming and that have no fields
+pub fn set_skip_empty(enabled: bool) {
+    SKIP_EMPTY.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether a location repeating the previous record's is omitted
+static DEDUP_LOCATION: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    /// When this thread last logged (for `set_show_delta`)
+    static LAST_LOG: Cell<Option<Instant>> = const { Cell::new(None) };
+
+    /// Location of this thread's last record that had one (for `set_dedup_location`)
+    static LAST_LOCATION: RefCell<Option<(String, u32)>> = const { RefCell::new(None) };
+}
+
+/// Show the time since the previous log on the same thread, e.g. `(+3.2ms)`
+///
+/// The first line on each thread shows `(+0ms)`.
+pub fn set_show_delta(enabled: bool) {
+    SHOW_DELTA.store(enabled, Ordering::Relaxed);
+}
+
+/// Most fields written per record (extra ones are replaced by a marker)
+static MAX_FIELDS: AtomicUsize = AtomicUsize::new(usize::MAX);
+
+/// Write at most `max` fields per record
+///
+/// Extra fields are dropped and replaced by a single `…(+K more)` marker,
+/// written as a field with an empty key.
+pub fn set_max_fields(max: usize) {
+    MAX_FIELDS.store(max, Ordering::Relaxed);
+}
+
+/// Show a record's source location only when it differs from the previous one
+///
+/// Tracked per thread; records without a location leave the last one as is.
+pub fn set_dedup_location(enabled: bool) {
+    DEDUP_LOCATION.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether `record`'s location repeats this thread's last one (updating it)
+fn repeats_location(record: &Record) -> bool {
+    let (Some(file), Some(line)) = (record.file(), record.line()) else {
+        return false;
+    };
+    LAST_LOCATION.with(|last| {
+        let mut last = last.borrow_mut();
+        if last
+            .as_ref()
+            .is_some_and(|(last_file, last_line)| last_file == file && *last_line == line)
+        {
+            return true;
+        }
+        *last = Some((file.to_string(), line));
+        false
+    })
+}
+
+/// Time since this thread last logged, resetting the mark
+fn delta_since_last() -> Duration {
+    let now = Instant::now();
+    LAST_LOG
+        .with(|last| last.replace(Some(now)))
+        .map_or(Duration::ZERO, |previous| now - previous)
+}
+
+/// Log a record to the configured output
+///
+/// `Level::Off` is a filter level; records at `Off` are ignored (and trip a
+/// debug assertion).
+pub fn log(record: &Record) {
+    dispatch(record, None);
+}
+
+/// Log a record to `writer` instead of the configured output
+///
+/// Level filtering and formatting still apply, and the global output is left
+/// untouched, e.g. for sending a single audit line to its own file.
+pub fn log_to<W: RecordWriter>(writer: &mut W, record: &Record) {
+    dispatch(record, Some(writer));
+}
+
+/// Run `record` through the filters and formatter and write it
+fn dispatch(record: &Record, output: Option<&mut dyn RecordWriter>) {
+    let level = record.level();
+    debug_assert!(level != Level::Off, "Level::Off cannot be logged");
+    if level == Level::Off {
+        return;
+    }
+    if level < level_for(record.target()) || sheds(level) {
+        stats().record_suppressed_by_level();
+        return;
+    }
+    if !sampled(record.target(), level) {
+        stats().record_suppressed_by_sampling();
+        return;
+    }
+    if SKIP_EMPTY.load(Ordering::Relaxed)
+        && record.message().trim().is_empty()
+        && record.fields().is_empty()
+    {
+        return;
+    }
+
+    let seq = SEQ.fetch_add(1, Ordering::Relaxed) + 1;
+    let thread = thread_label();
+    let trace = trace_context();
+    let span = span_fields();
+    let id = show_record_id().then(|| record_id(seq));
+    let mut record = *record;
+    if SHOW_SEQ.load(Ordering::Relaxed) {
+        record = record.with_seq(seq);
+    }
+    if SHOW_DELTA.load(Ordering::Relaxed) {
+        record = record.with_delta(delta_since_last());
+    }
+    if let Some(thread) = &thread {
+        record = record.with_thread(thread);
+    }
+    if DEDUP_LOCATION.load(Ordering::Relaxed) && repeats_location(&record) {
+        record = record.without_location();
+    }
+
+    on_record(&record);
+    let message = indent(sanitize_message(record.message()));
+    let none = none_rendering();
+    let guarded: Vec<_> = record
+        .fields()
+        .iter()
+        .filter_map(|(key, value)| {
+            if none != NoneRendering::Literal && is_none(*value) {
+                return (none == NoneRendering::Null).then_some((*key, None));
+            }
+            Some((*key, Some(Sanitized(PanicSafe(*value)))))
+        })
+        .collect();
+    let max_fields = MAX_FIELDS.load(Ordering::Relaxed);
+    let more =
+        (guarded.len() > max_fields).then(|| format!("…(+{} more)", guarded.len() - max_fields));
+    let mut fields: Vec<Field> = guarded
+        .iter()
+        .take(max_fields)
+        .map(|(key, value)| {
+            (
+                *key,
+                value.as_ref().map_or(null_field(), |value| value as _),
+            )
+        })
+        .collect();
+    if let Some(more) = &more {
+        fields.push(("", more));
+    }
+    for (key, value) in &span {
+        fields.push((key, value));
+    }
+    if let Some(context) = &trace {
+        fields.push(("trace_id", &context.trace_id));
+        fields.push(("span_id", &context.span_id));
+    }
+    if let Some(id) = &id {
+        fields.push(("record_id", id));
+    }
+    let record = record.with_message(&message).with_fields(&fields);
+    write_log(&record, output);
+    publish(&record);
+    stats().record_emitted(level);
+    observe_level(level);
+    note_emitted();
+}
+
+/// Log a message built by `message`, which is only called if `level` is enabled
+pub fn log_lazy<F: FnOnce() -> String>(level: Level, message: F) {
+    if !log_enabled(level) {
+        stats().record_suppressed_by_level();
+        return;
+    }
+
+    let message = message();
+    log(&Record::new(level, &message));
+}
+
+/// Log a plain message at `level`
+///
+/// The label and color come from `level`, and the usual level filters apply.
+pub fn write_record(level: Level, message: &str) {
+    log(&Record::new(level, message));
+}
+
+/// Log a message with structured key-value fields
+///
+/// The non-macro way to attach fields, for code that builds them at runtime.
+pub fn log_kv(level: Level, message: &str, fields: &[Field]) {
+    log(&Record::new(level, message).with_fields(fields));
+}
+
+/// How `log_error` renders an error's `source()` chain
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ErrorChain {
+    /// One message: `outer: caused by: inner: caused by: root` (default)
+    #[default]
+    Joined,
+    /// The outer error as the message and each cause as a `cause_N` field
+    Fields,
+}
+
+/// Log `err` together with its `source()` chain, located at the caller's call site
+#[track_caller]
+pub fn log_error(level: Level, err: &dyn Error, chain: ErrorChain) {
+    let location = Location::caller();
+    if !log_enabled(level) {
+        stats().record_suppressed_by_level();
+        return;
+    }
+
+    let mut causes = Vec::new();
+    let mut source = err.source();
+    while let Some(cause) = source {
+        causes.push(format_safe(format_args!("{cause}")));
+        source = cause.source();
+    }
+
+    let mut message = format_safe(format_args!("{err}"));
+    let record = Record::new(level, "").with_location(location.file(), location.line());
+    match chain {
+        ErrorChain::Joined => {
+            for cause in &causes {
+                message.push_str(": caused by: ");
+                message.push_str(cause);
+            }
+            log(&record.with_message(&message));
+        }
+        ErrorChain::Fields => {
+            let keys: Vec<String> = (1..=causes.len()).map(|n| format!("cause_{n}")).collect();
+            let fields: Vec<Field> = keys
+                .iter()
+                .zip(&causes)
+                .map(|(key, cause)| (key.as_str(), cause as _))
+                .collect();
+            log(&record.with_message(&message).with_fields(&fields));
+        }
+    }
+}
+
+/// Log a formatted message located at the caller's call site
+///
+/// Mark helper functions `#[track_caller]` so the recorded location points at
+/// their caller rather than the helper itself.
+#[track_caller]
+pub fn log_at_caller(level: Level, args: fmt::Arguments) {
+    let location = Location::caller();
+    if !log_enabled(level) {
+        stats().record_suppressed_by_level();
+        return;
+    }
+
+    let message = format_safe(args);
+    log(&Record::new(level, &message).with_location(location.file(), location.line()));
+}
+
+/// Log raw bytes, escaping backslashes, control characters and invalid UTF-8
+///
+/// Backslashes become `\\` and every other unprintable byte becomes `\xNN`,
+/// so the original bytes can always be recovered from the output.
+pub fn log_bytes(level: Level, bytes: &[u8]) {
+    log_lazy(level, || escape_bytes(bytes));
+}
 
 /// Log a debug message (cyan)
 pub fn debug(message: &str) {
-    write_log("debg", "\x1b[36m", message);
+    log(&Record::new(Level::Debug, message));
 }
 
 /// Log an info message (green)
 pub fn info(message: &str) {
-    write_log("info", "\x1b[32m", message);
+    log(&Record::new(Level::Info, message));
 }
 
 /// Log a warning message (yellow)
 pub fn warn(message: &str) {
-    write_log("warn", "\x1b[33m", message);
+    log(&Record::new(Level::Warn, message));
 }
 
 /// Log an error message (red)
 pub fn error(message: &str) {
-    write_log("errr", "\x1b[31m", message);
+    log(&Record::new(Level::Error, message));
 }
@@ -0,0 +1,170 @@
+/// Log a formatted message at a level, with an optional `target:`
+///
+/// Calls below `STATIC_MAX_LEVEL` are compiled out, and arguments are only
+/// formatted when the record passes the runtime level filters.
+///
+/// ```ignore
+/// log!(Level::Info, "listening on {}", port);
+/// log!(target: "audit", Level::Warn, "user {} deleted", id);
+/// ```
+#[macro_export]
+macro_rules! log {
+    (target: $target:expr, $level:expr, $($arg:tt)+) => {{
+        let level = $level;
+        let target = $target;
+        if level >= $crate::STATIC_MAX_LEVEL && $crate::__private::macro_enabled(level, target) {
+            let message = $crate::__private::format_safe(format_args!($($arg)+));
+            let record = $crate::Record::new(level, &message)
+                .with_module_path(module_path!())
+                .with_location(file!(), line!())
+                .with_target(target);
+            $crate::log(&$crate::__with_template!(record; $($arg)+));
+        }
+    }};
+    ($level:expr, $($arg:tt)+) => {{
+        let level = $level;
+        if level >= $crate::STATIC_MAX_LEVEL
+            && $crate::__private::macro_enabled(level, module_path!())
+        {
+            let message = $crate::__private::format_safe(format_args!($($arg)+));
+            let record = $crate::Record::new(level, &message)
+                .with_module_path(module_path!())
+                .with_location(file!(), line!());
+            $crate::log(&$crate::__with_template!(record; $($arg)+));
+        }
+    }};
+}
+
+/// Attach the format string to `record` when it is a literal
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __with_template {
+    ($record:expr; $template:literal $($rest:tt)*) => {
+        $record.with_template($template)
+    };
+    ($record:expr; $($rest:tt)*) => {
+        $record
+    };
+}
+
+/// Log a formatted debug message
+#[macro_export]
+macro_rules! debug {
+    (target: $target:expr, $($arg:tt)+) => { $crate::log!(target: $target, $crate::Level::Debug, $($arg)+) };
+    ($($arg:tt)+) => { $crate::log!($crate::Level::Debug, $($arg)+) };
+}
+
+/// Log a formatted info message
+#[macro_export]
+macro_rules! info {
+    (target: $target:expr, $($arg:tt)+) => { $crate::log!(target: $target, $crate::Level::Info, $($arg)+) };
+    ($($arg:tt)+) => { $crate::log!($crate::Level::Info, $($arg)+) };
+}
+
+/// Log a formatted warning message
+#[macro_export]
+macro_rules! warn {
+    (target: $target:expr, $($arg:tt)+) => { $crate::log!(target: $target, $crate::Level::Warn, $($arg)+) };
+    ($($arg:tt)+) => { $crate::log!($crate::Level::Warn, $($arg)+) };
+}
+
+/// Log a formatted error message
+#[macro_export]
+macro_rules! error {
+    (target: $target:expr, $($arg:tt)+) => { $crate::log!(target: $target, $crate::Level::Error, $($arg)+) };
+    ($($arg:tt)+) => { $crate::log!($crate::Level::Error, $($arg)+) };
+}
+
+/// Time a block, logging its duration at debug level when it completes
+///
+/// ```ignore
+/// let rows = timed!("query users", { db.query() });
+/// ```
+#[macro_export]
+macro_rules! timed {
+    ($label:expr, $body:block) => {{
+        let _timer = $crate::Timer::new($label);
+        $body
+    }};
+}
+
+/// Open a span with optional `key = value` fields, returning its guard
+///
+/// ```ignore
+/// let _span = span!("handle_request", req_id = id, user = name);
+/// ```
+#[macro_export]
+macro_rules! span {
+    ($name:expr $(, $key:ident = $value:expr)* $(,)?) => {
+        $crate::Span::enter(
+            $name,
+            &[$((stringify!($key), &$value as &dyn ::core::fmt::Display)),*],
+        )
+    };
+}
+
+/// Log the named fields of a struct as a structured record
+///
+/// The message is the variable name and each listed field (which must
+/// implement `Display`) becomes a key-value field.
+///
+/// ```ignore
+/// log_fields!(Level::Debug, config { host, port });
+/// // => debug config host=localhost port=8080
+/// ```
+#[macro_export]
+macro_rules! log_fields {
+    ($level:expr, $value:ident { $($field:ident),* $(,)? }) => {{
+        let level = $level;
+        if level >= $crate::STATIC_MAX_LEVEL
+            && $crate::__private::macro_enabled(level, module_path!())
+        {
+            let value = &$value;
+            $crate::log(
+                &$crate::Record::new(level, stringify!($value))
+                    .with_module_path(module_path!())
+                    .with_location(file!(), line!())
+                    .with_fields(&[$((
+                        stringify!($field),
+                        &value.$field as &dyn ::core::fmt::Display,
+                    )),*]),
+            );
+        }
+    }};
+}
+
+/// Write a formatted audit record at info level, bypassing all filters
+///
+/// ```ignore
+/// audit!("user {} deleted account {}", user, account);
+/// ```
+#[macro_export]
+macro_rules! audit {
+    ($($arg:tt)+) => {{
+        let message = $crate::__private::format_safe(format_args!($($arg)+));
+        let record = $crate::Record::new($crate::Level::Info, &message)
+            .with_module_path(module_path!())
+            .with_location(file!(), line!());
+        $crate::audit(&$crate::__with_template!(record; $($arg)+));
+    }};
+}
+
+/// Log an error with its `source()` chain at a level
+///
+/// The chain is joined into the message unless an `ErrorChain` is given.
+///
+/// ```ignore
+/// log_error!(Level::Error, err);
+/// // => errr connection failed: caused by: timeout: caused by: dns failure
+/// log_error!(Level::Error, err, ErrorChain::Fields);
+/// // => errr connection failed cause_1=timeout cause_2=dns failure
+/// ```
+#[macro_export]
+macro_rules! log_error {
+    ($level:expr, $err:expr $(,)?) => {
+        $crate::log_error($level, &$err, $crate::ErrorChain::Joined)
+    };
+    ($level:expr, $err:expr, $chain:expr $(,)?) => {
+        $crate::log_error($level, &$err, $chain)
+    };
+}
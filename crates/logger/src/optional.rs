@@ -0,0 +1,94 @@
+use crate::utils::{read, write};
+use std::fmt;
+use std::sync::RwLock;
+
+/// How `None` values of `OptionField`s are rendered
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NoneRendering {
+    /// Drop the field from the record
+    Omit,
+    /// `null` in JSON field output, an empty value in text (`key=`)
+    Null,
+    /// The literal text `none` (default)
+    #[default]
+    Literal,
+}
+
+static NONE_RENDERING: RwLock<NoneRendering> = RwLock::new(NoneRendering::Literal);
+
+/// Set how `None` values of `OptionField`s are rendered
+pub fn set_none_rendering(mode: NoneRendering) {
+    *write(&NONE_RENDERING) = mode;
+}
+
+/// The configured `NoneRendering`
+pub(crate) fn none_rendering() -> NoneRendering {
+    *read(&NONE_RENDERING)
+}
+
+/// Field wrapping an `Option`, rendered per `set_none_rendering` when `None`
+///
+/// Pass `value()` as the field value, so dispatch can tell a `None` apart
+/// without formatting it:
+///
+/// ```ignore
+/// let user = OptionField(user_id);
+/// log_kv(Level::Info, "login", &[("user", user.value())]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct OptionField<T>(pub Option<T>);
+
+impl<T: fmt::Display> OptionField<T> {
+    /// The wrapped value, or a stand-in dispatch recognizes as `None`
+    pub fn value(&self) -> &dyn fmt::Display {
+        match &self.0 {
+            Some(value) => value,
+            None => &NONE_FIELD,
+        }
+    }
+}
+
+/// Stand-in value of a `None` field, recognized by its address
+///
+/// Not zero-sized, so its address is unique. Renders as `none` under
+/// `Literal` and as nothing otherwise, for code formatting it directly.
+struct NoneField(&'static str);
+
+impl fmt::Display for NoneField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match none_rendering() {
+            NoneRendering::Literal => f.write_str(self.0),
+            NoneRendering::Omit | NoneRendering::Null => Ok(()),
+        }
+    }
+}
+
+static NONE_FIELD: NoneField = NoneField("none");
+
+/// Whether `value` is the stand-in for a `None` `OptionField`
+pub(crate) fn is_none(value: &dyn fmt::Display) -> bool {
+    std::ptr::addr_eq(value, &NONE_FIELD)
+}
+
+/// Stand-in value of a `None` field under `NoneRendering::Null`, holding its text rendering
+///
+/// Not zero-sized, so its address identifies it in `is_null`.
+struct NullField(&'static str);
+
+impl fmt::Display for NullField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+static NULL_FIELD: NullField = NullField("");
+
+/// The value dispatch substitutes for a `None` field under `NoneRendering::Null`
+pub(crate) fn null_field() -> &'static dyn fmt::Display {
+    &NULL_FIELD
+}
+
+/// Whether `value` is the `null_field` stand-in
+pub(crate) fn is_null(value: &dyn fmt::Display) -> bool {
+    std::ptr::addr_eq(value, &NULL_FIELD)
+}
@@ -0,0 +1,358 @@
+use crate::level::Level;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::time::Duration;
+
+/// A structured key-value pair attached to a record
+pub type Field<'a> = (&'a str, &'a dyn fmt::Display);
+
+/// A single log event passed through the logging pipeline
+#[derive(Clone, Copy)]
+pub struct Record<'a> {
+    level: Level,
+    message: &'a str,
+    target: Option<&'a str>,
+    module_path: Option<&'a str>,
+    file: Option<&'a str>,
+    line: Option<u32>,
+    seq: Option<u64>,
+    delta: Option<Duration>,
+    thread: Option<&'a str>,
+    time: Option<Duration>,
+    template: Option<&'static str>,
+    fields: &'a [Field<'a>],
+}
+
+impl<'a> Record<'a> {
+    pub fn new(level: Level, message: &'a str) -> Self {
+        Self {
+            level,
+            message,
+            target: None,
+            module_path: None,
+            file: None,
+            line: None,
+            seq: None,
+            delta: None,
+            thread: None,
+            time: None,
+            template: None,
+            fields: &[],
+        }
+    }
+
+    /// Set an explicit target (used for filtering instead of the module path)
+    pub fn with_target(mut self, target: &'a str) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// Set the module path of the call site
+    pub fn with_module_path(mut self, module_path: &'a str) -> Self {
+        self.module_path = Some(module_path);
+        self
+    }
+
+    /// Set the source location of the call site
+    pub fn with_location(mut self, file: &'a str, line: u32) -> Self {
+        self.file = Some(file);
+        self.line = Some(line);
+        self
+    }
+
+    /// Drop the source location (e.g. when it repeats the previous record's)
+    #[cfg(feature = "std")]
+    pub(crate) fn without_location(mut self) -> Self {
+        self.file = None;
+        self.line = None;
+        self
+    }
+
+    /// Replace the message text
+    #[cfg(feature = "std")]
+    pub(crate) fn with_message(mut self, message: &'a str) -> Self {
+        self.message = message;
+        self
+    }
+
+    /// Set the sequence number (assigned by the logger when enabled)
+    #[cfg(feature = "std")]
+    pub(crate) fn with_seq(mut self, seq: u64) -> Self {
+        self.seq = Some(seq);
+        self
+    }
+
+    /// Set the time since the previous record on this thread
+    #[cfg(feature = "std")]
+    pub(crate) fn with_delta(mut self, delta: Duration) -> Self {
+        self.delta = Some(delta);
+        self
+    }
+
+    /// Set the label of the logging thread
+    #[cfg(feature = "std")]
+    pub(crate) fn with_thread(mut self, thread: &'a str) -> Self {
+        self.thread = Some(thread);
+        self
+    }
+
+    /// Set the time the record was written, as time since the Unix epoch
+    pub fn with_time(mut self, time: Duration) -> Self {
+        self.time = Some(time);
+        self
+    }
+
+    /// Set the format string the message was built from (set by the macros)
+    pub fn with_template(mut self, template: &'static str) -> Self {
+        self.template = Some(template);
+        self
+    }
+
+    /// Attach structured key-value fields
+    pub fn with_fields(mut self, fields: &'a [Field<'a>]) -> Self {
+        self.fields = fields;
+        self
+    }
+
+    /// Set the severity in place
+    ///
+    /// The `set_*` methods mirror the consuming `with_*` builders for
+    /// building a record incrementally, e.g. inside a loop or `if`.
+    pub fn set_level(&mut self, level: Level) -> &mut Self {
+        self.level = level;
+        self
+    }
+
+    /// Set the message text in place
+    pub fn set_message(&mut self, message: &'a str) -> &mut Self {
+        self.message = message;
+        self
+    }
+
+    /// Set the target in place
+    pub fn set_target(&mut self, target: &'a str) -> &mut Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// Set the module path in place
+    pub fn set_module_path(&mut self, module_path: &'a str) -> &mut Self {
+        self.module_path = Some(module_path);
+        self
+    }
+
+    /// Set the source file in place
+    pub fn set_file(&mut self, file: &'a str) -> &mut Self {
+        self.file = Some(file);
+        self
+    }
+
+    /// Set the source line in place
+    pub fn set_line(&mut self, line: u32) -> &mut Self {
+        self.line = Some(line);
+        self
+    }
+
+    /// Set the record time in place, as time since the Unix epoch
+    pub fn set_time(&mut self, time: Duration) -> &mut Self {
+        self.time = Some(time);
+        self
+    }
+
+    /// Set the structured fields in place
+    pub fn set_fields(&mut self, fields: &'a [Field<'a>]) -> &mut Self {
+        self.fields = fields;
+        self
+    }
+
+    /// Severity of the record
+    pub fn level(&self) -> Level {
+        self.level
+    }
+
+    /// Message text
+    pub fn message(&self) -> &'a str {
+        self.message
+    }
+
+    /// Explicit target if set, otherwise the module path
+    pub fn target(&self) -> Option<&'a str> {
+        self.target.or(self.module_path)
+    }
+
+    /// Module path of the call site
+    pub fn module_path(&self) -> Option<&'a str> {
+        self.module_path
+    }
+
+    /// Source file of the call site
+    pub fn file(&self) -> Option<&'a str> {
+        self.file
+    }
+
+    /// Source line of the call site
+    pub fn line(&self) -> Option<u32> {
+        self.line
+    }
+
+    /// Per-process sequence number, if `set_show_seq` is enabled
+    pub fn seq(&self) -> Option<u64> {
+        self.seq
+    }
+
+    /// Time since the previous record on this thread, if `set_show_delta` is enabled
+    pub fn delta(&self) -> Option<Duration> {
+        self.delta
+    }
+
+    /// Logging thread, if `set_show_thread` is enabled
+    pub fn thread(&self) -> Option<&'a str> {
+        self.thread
+    }
+
+    /// Time since the Unix epoch, set by the logger when the line is formatted
+    ///
+    /// Lets custom formatters render the time themselves instead of using
+    /// the pre-rendered timestamp.
+    pub fn time(&self) -> Option<Duration> {
+        self.time
+    }
+
+    /// Format string of the message before interpolation, e.g. `"user {} deleted"`
+    pub fn template(&self) -> Option<&'static str> {
+        self.template
+    }
+
+    /// Stable key grouping records from the same log statement
+    ///
+    /// A 64-bit FNV-1a hash of the level and the template (or the message
+    /// when there is none), so calls differing only in their arguments,
+    /// timestamps or fields share a key. Stable across runs and builds.
+    pub fn dedup_key(&self) -> u64 {
+        const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        let text = self.template.unwrap_or(self.message);
+        [self.level as u8]
+            .iter()
+            .chain(text.as_bytes())
+            .fold(OFFSET, |hash, byte| {
+                (hash ^ u64::from(*byte)).wrapping_mul(PRIME)
+            })
+    }
+
+    /// Structured key-value fields
+    pub fn fields(&self) -> &'a [Field<'a>] {
+        self.fields
+    }
+}
+
+impl fmt::Debug for Record<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let fields: Vec<(&str, String)> = self
+            .fields
+            .iter()
+            .map(|(key, value)| (*key, value.to_string()))
+            .collect();
+        f.debug_struct("Record")
+            .field("level", &self.level)
+            .field("message", &self.message)
+            .field("target", &self.target)
+            .field("module_path", &self.module_path)
+            .field("file", &self.file)
+            .field("line", &self.line)
+            .field("seq", &self.seq)
+            .field("delta", &self.delta)
+            .field("thread", &self.thread)
+            .field("time", &self.time)
+            .field("fields", &fields)
+            .finish()
+    }
+}
+
+/// A record that owns its data, so it can be stored and logged later
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnedRecord {
+    level: Level,
+    message: String,
+    target: Option<String>,
+    module_path: Option<String>,
+    file: Option<String>,
+    line: Option<u32>,
+    fields: Vec<(String, String)>,
+}
+
+impl OwnedRecord {
+    pub fn new(level: Level, message: impl Into<String>) -> Self {
+        Self {
+            level,
+            message: message.into(),
+            target: None,
+            module_path: None,
+            file: None,
+            line: None,
+            fields: Vec::new(),
+        }
+    }
+
+    /// Attach a structured field
+    pub fn with_field(mut self, key: &str, value: impl fmt::Display) -> Self {
+        self.fields.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Severity of the record
+    pub fn level(&self) -> Level {
+        self.level
+    }
+
+    /// Message text
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Structured key-value fields (values already rendered)
+    pub fn fields(&self) -> &[(String, String)] {
+        &self.fields
+    }
+
+    /// Run `f` with a borrowed view of this record
+    pub fn with_record<R>(&self, f: impl FnOnce(&Record) -> R) -> R {
+        let fields: Vec<Field> = self
+            .fields
+            .iter()
+            .map(|(key, value)| (key.as_str(), value as &dyn fmt::Display))
+            .collect();
+
+        let mut record = Record::new(self.level, &self.message).with_fields(&fields);
+        record.target = self.target.as_deref();
+        record.module_path = self.module_path.as_deref();
+        record.file = self.file.as_deref();
+        record.line = self.line;
+        f(&record)
+    }
+
+    /// Log this record to the configured output
+    pub fn log(&self) {
+        self.with_record(crate::log);
+    }
+}
+
+impl From<&Record<'_>> for OwnedRecord {
+    fn from(record: &Record<'_>) -> Self {
+        Self {
+            level: record.level,
+            message: record.message.to_string(),
+            target: record.target.map(str::to_string),
+            module_path: record.module_path.map(str::to_string),
+            file: record.file.map(str::to_string),
+            line: record.line,
+            fields: record
+                .fields
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+        }
+    }
+}
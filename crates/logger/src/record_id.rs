@@ -0,0 +1,50 @@
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::LazyLock;
+
+/// Length of a record id
+pub const RECORD_ID_LEN: usize = 8;
+
+/// Base62 alphabet of record ids
+const ALPHABET: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Number of distinct ids (62^8)
+const ID_SPACE: u64 = 62u64.pow(RECORD_ID_LEN as u32);
+
+/// Multiplier spreading consecutive counters over the id space (coprime with it)
+const SPREAD: u64 = 86_206_207_853_845;
+
+/// Random per-process offset, so runs do not repeat each other's ids
+static SALT: LazyLock<u64> = LazyLock::new(|| RandomState::new().hash_one(std::process::id()));
+
+/// Whether records carry a `record_id` field
+static SHOW_RECORD_ID: AtomicBool = AtomicBool::new(false);
+
+/// Add a short unique `record_id` field (8 base62 characters) to every record
+///
+/// Ids are unique within a process run and cheap to generate (no RNG per
+/// record), so a line can be referenced as e.g. `record id 4fK9x0Qa`.
+pub fn set_show_record_id(enabled: bool) {
+    SHOW_RECORD_ID.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether records carry a `record_id` field
+pub(crate) fn show_record_id() -> bool {
+    SHOW_RECORD_ID.load(Ordering::Relaxed)
+}
+
+/// Id of the record with sequence number `seq`
+///
+/// Multiplying by a constant coprime with the id space is a bijection, so
+/// distinct sequence numbers map to distinct ids until the space wraps.
+pub(crate) fn record_id(seq: u64) -> String {
+    let spread = (u128::from(seq) * u128::from(SPREAD)) % u128::from(ID_SPACE);
+    let mut value = (spread as u64 + *SALT % ID_SPACE) % ID_SPACE;
+    let mut id = [0u8; RECORD_ID_LEN];
+    for slot in id.iter_mut().rev() {
+        *slot = ALPHABET[(value % 62) as usize];
+        value /= 62;
+    }
+    id.iter().map(|&byte| byte as char).collect()
+}
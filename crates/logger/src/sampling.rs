@@ -0,0 +1,77 @@
+use crate::filter::matches;
+use crate::level::Level;
+use crate::utils::{read, write};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::RwLock;
+
+/// A sampling rule for one target prefix
+struct SamplingRule {
+    prefix: String,
+    level: Level,
+    ratio: f64,
+    /// Records considered by this rule so far
+    seen: AtomicU64,
+}
+
+/// Configured rules (checked only when `HAS_RULES` is set)
+static RULES: RwLock<Vec<SamplingRule>> = RwLock::new(Vec::new());
+
+/// Whether any sampling rules are configured
+static HAS_RULES: AtomicBool = AtomicBool::new(false);
+
+/// Keep only a fraction of the records for some targets, as (pattern, level, ratio)
+///
+/// A pattern is a target or module path prefix, optionally ending in `::*`
+/// (`payments::*` matches `payments` and its submodules). The longest matching
+/// pattern wins; records at or below its level keep `ratio` of their lines
+/// (`1.0` keeps all, `0.01` one in a hundred), and more severe ones are
+/// always kept. Sampling is deterministic: exactly every `1/ratio`th record
+/// is written. Replaces any previous rules; pass an empty list to clear them.
+pub fn set_sampling_rules(rules: Vec<(String, Level, f64)>) {
+    let rules: Vec<SamplingRule> = rules
+        .into_iter()
+        .map(|(pattern, level, ratio)| SamplingRule {
+            prefix: pattern.strip_suffix("::*").unwrap_or(&pattern).to_string(),
+            level,
+            ratio: ratio.clamp(0.0, 1.0),
+            seen: AtomicU64::new(0),
+        })
+        .collect();
+    let mut current = write(&RULES);
+    HAS_RULES.store(!rules.is_empty(), Ordering::Relaxed);
+    *current = rules;
+}
+
+/// Get the configured rules as (prefix, level, ratio)
+pub(crate) fn sampling_rules() -> Vec<(String, Level, f64)> {
+    read(&RULES)
+        .iter()
+        .map(|rule| (rule.prefix.clone(), rule.level, rule.ratio))
+        .collect()
+}
+
+/// Whether a record at `level` for `target` survives sampling
+pub(crate) fn sampled(target: Option<&str>, level: Level) -> bool {
+    if !HAS_RULES.load(Ordering::Relaxed) {
+        return true;
+    }
+    let Some(target) = target else {
+        return true;
+    };
+
+    let rules = read(&RULES);
+    let Some(rule) = rules
+        .iter()
+        .filter(|rule| matches(target, &rule.prefix))
+        .max_by_key(|rule| rule.prefix.len())
+    else {
+        return true;
+    };
+    if level > rule.level {
+        return true;
+    }
+
+    // Keep the record whenever the running count of kept records ticks over
+    let seen = rule.seen.fetch_add(1, Ordering::Relaxed);
+    (((seen + 1) as f64) * rule.ratio).floor() > ((seen as f64) * rule.ratio).floor()
+}
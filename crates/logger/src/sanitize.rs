@@ -0,0 +1,92 @@
+use crate::utils::{escape_controls, escape_newlines, escape_nul};
+use std::borrow::Cow;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether control characters in messages are escaped
+static SANITIZE: AtomicBool = AtomicBool::new(false);
+
+/// Whether newlines in messages are escaped (on by default)
+static ESCAPE_NEWLINES: AtomicBool = AtomicBool::new(true);
+
+/// Whether NUL bytes in messages and field values are escaped (on by default)
+static ESCAPE_NUL: AtomicBool = AtomicBool::new(true);
+
+/// Whether trailing whitespace is trimmed from messages (on by default)
+static TRIM_MESSAGE: AtomicBool = AtomicBool::new(true);
+
+/// Escape control characters (ANSI escapes, newlines, ...) in messages and field values
+///
+/// Protects terminals tailing the logs from injected escape sequences and
+/// stops one log call from forging extra lines. The logger's own coloring is
+/// unaffected.
+pub fn set_sanitize_messages(enabled: bool) {
+    SANITIZE.store(enabled, Ordering::Relaxed);
+}
+
+/// Escape `\n` and `\r` in messages so one call always produces one line (default on)
+///
+/// Disable only when multi-line messages are intended and the input is trusted.
+pub fn set_escape_newlines(enabled: bool) {
+    ESCAPE_NEWLINES.store(enabled, Ordering::Relaxed);
+}
+
+/// Escape NUL bytes in messages and field values as `\0` (default on)
+///
+/// C-based sinks such as syslog or journald would otherwise silently
+/// truncate the line at the first NUL.
+pub fn set_escape_nul(enabled: bool) {
+    ESCAPE_NUL.store(enabled, Ordering::Relaxed);
+}
+
+/// Trim trailing whitespace, such as a `println!`-style `\n`, from messages (default on)
+///
+/// Leading whitespace is kept, as it may be intentional indentation.
+pub fn set_trim_message(enabled: bool) {
+    TRIM_MESSAGE.store(enabled, Ordering::Relaxed);
+}
+
+/// Get whether controls, newlines and NUL bytes are escaped
+pub(crate) fn escape_settings() -> (bool, bool, bool) {
+    (
+        SANITIZE.load(Ordering::Relaxed),
+        ESCAPE_NEWLINES.load(Ordering::Relaxed),
+        ESCAPE_NUL.load(Ordering::Relaxed),
+    )
+}
+
+/// Apply the configured trimming and sanitization to a message
+pub(crate) fn sanitize_message(message: &str) -> Cow<'_, str> {
+    let message = if TRIM_MESSAGE.load(Ordering::Relaxed) {
+        message.trim_end()
+    } else {
+        message
+    };
+    if SANITIZE.load(Ordering::Relaxed) {
+        return escape_controls(message);
+    }
+    let message = if ESCAPE_NEWLINES.load(Ordering::Relaxed) {
+        escape_newlines(message)
+    } else {
+        Cow::Borrowed(message)
+    };
+    if ESCAPE_NUL.load(Ordering::Relaxed) && message.contains('\0') {
+        return Cow::Owned(escape_nul(&message).into_owned());
+    }
+    message
+}
+
+/// `Display` wrapper applying the configured NUL or control escaping to a field value
+pub(crate) struct Sanitized<T>(pub T);
+
+impl<T: fmt::Display> fmt::Display for Sanitized<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if SANITIZE.load(Ordering::Relaxed) {
+            f.write_str(&escape_controls(&self.0.to_string()))
+        } else if ESCAPE_NUL.load(Ordering::Relaxed) {
+            f.write_str(&escape_nul(&self.0.to_string()))
+        } else {
+            self.0.fmt(f)
+        }
+    }
+}
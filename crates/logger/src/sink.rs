@@ -0,0 +1,88 @@
+use crate::format::{Formatter, TextFormatter};
+use crate::level::{log_enabled, Level};
+use crate::record::Record;
+use alloc::string::String;
+use core::fmt;
+use core::sync::atomic::{AtomicU8, Ordering};
+
+/// Destination for log lines when built without `std`
+pub trait Sink: Sync {
+    /// Write the formatted `line` (without terminator) for `record`
+    fn write_line(&self, record: &Record, line: &str);
+}
+
+const UNINITIALIZED: u8 = 0;
+const INITIALIZING: u8 = 1;
+const INITIALIZED: u8 = 2;
+
+/// Initialization state of `SINK`
+static STATE: AtomicU8 = AtomicU8::new(UNINITIALIZED);
+
+/// Global sink, written once by `set_sink`
+static mut SINK: Option<&'static dyn Sink> = None;
+
+/// Error returned when a sink has already been installed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetSinkError(());
+
+impl fmt::Display for SetSinkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a log sink has already been set")
+    }
+}
+
+impl core::error::Error for SetSinkError {}
+
+/// Install the global sink (can only be done once)
+pub fn set_sink(sink: &'static dyn Sink) -> Result<(), SetSinkError> {
+    match STATE.compare_exchange(
+        UNINITIALIZED,
+        INITIALIZING,
+        Ordering::Acquire,
+        Ordering::Relaxed,
+    ) {
+        Ok(_) => {
+            // SAFETY: the state transition above grants exclusive access, and
+            // readers only look at SINK once STATE is INITIALIZED
+            unsafe { SINK = Some(sink) };
+            STATE.store(INITIALIZED, Ordering::Release);
+            Ok(())
+        }
+        Err(_) => Err(SetSinkError(())),
+    }
+}
+
+/// Get the installed sink, if any
+fn sink() -> Option<&'static dyn Sink> {
+    if STATE.load(Ordering::Acquire) != INITIALIZED {
+        return None;
+    }
+    // SAFETY: SINK is never written again once STATE is INITIALIZED
+    unsafe { SINK }
+}
+
+/// Check whether a record at `level` for `target` would be written
+///
+/// Without `std` there are no per-target overrides, so this matches
+/// `log_enabled`.
+pub fn enabled(_target: &str, level: Level) -> bool {
+    log_enabled(level)
+}
+
+/// Filter check run by the macros before formatting
+pub fn macro_enabled(level: Level, target: &str) -> bool {
+    enabled(target, level)
+}
+
+/// Log a record to the installed sink using the text format
+pub fn log(record: &Record) {
+    if !log_enabled(record.level()) {
+        return;
+    }
+
+    if let Some(sink) = sink() {
+        let mut line = String::new();
+        TextFormatter.format(record, "", &mut line);
+        sink.write_line(record, &line);
+    }
+}
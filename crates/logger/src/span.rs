@@ -0,0 +1,64 @@
+use crate::context::{pop_span_fields, push_span_fields};
+use crate::level::Level;
+use crate::loggers::log;
+use crate::record::{Field, Record};
+use std::fmt::Display;
+use std::time::Instant;
+
+/// Guard for a traced operation: logs `enter <name>` now and `exit <name>` on drop
+///
+/// The span's fields are attached to both events and to every log emitted
+/// on this thread while the span is open. The exit event also carries the
+/// elapsed time as a `duration` field. Usually created with `span!`.
+///
+/// ```ignore
+/// let _span = span!("handle_request", req_id = id);
+/// ```
+pub struct Span {
+    name: String,
+    start: Instant,
+    fields: Vec<(String, String)>,
+    depth: usize,
+}
+
+impl Span {
+    /// Open a span named `name` with `fields`, logging the enter event
+    pub fn enter(name: &str, fields: &[Field]) -> Self {
+        let fields: Vec<(String, String)> = fields
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        emit(&format!("enter {name}"), &fields, None);
+        let depth = push_span_fields(&fields);
+        Self {
+            name: name.to_string(),
+            start: Instant::now(),
+            fields,
+            depth,
+        }
+    }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        pop_span_fields(self.depth);
+        let duration = format!("{:.2?}", self.start.elapsed());
+        emit(
+            &format!("exit {}", self.name),
+            &self.fields,
+            Some(&duration),
+        );
+    }
+}
+
+/// Log a span event at info level
+fn emit(message: &str, fields: &[(String, String)], duration: Option<&String>) {
+    let mut fields: Vec<Field> = fields
+        .iter()
+        .map(|(key, value)| (key.as_str(), value as &dyn Display))
+        .collect();
+    if let Some(duration) = duration {
+        fields.push(("duration", duration));
+    }
+    log(&Record::new(Level::Info, message).with_fields(&fields));
+}
@@ -0,0 +1,131 @@
+use crate::level::{Level, LEVEL_COUNT};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicU8, Ordering};
+
+/// Global self-monitoring counters
+static STATS: Stats = Stats::new();
+
+/// Counters of emitted and suppressed records
+pub struct Stats {
+    emitted: [AtomicU64; LEVEL_COUNT],
+    suppressed_by_level: AtomicU64,
+    suppressed_by_sampling: AtomicU64,
+    write_failures: AtomicU64,
+    fallback_writes: AtomicU64,
+}
+
+impl Stats {
+    const fn new() -> Self {
+        Self {
+            emitted: [const { AtomicU64::new(0) }; LEVEL_COUNT],
+            suppressed_by_level: AtomicU64::new(0),
+            suppressed_by_sampling: AtomicU64::new(0),
+            write_failures: AtomicU64::new(0),
+            fallback_writes: AtomicU64::new(0),
+        }
+    }
+
+    /// Number of records emitted at `level`
+    pub fn emitted(&self, level: Level) -> u64 {
+        self.emitted
+            .get(level as usize)
+            .map_or(0, |counter| counter.load(Ordering::Relaxed))
+    }
+
+    /// Number of records emitted across all levels
+    pub fn total_emitted(&self) -> u64 {
+        self.emitted.iter().map(|c| c.load(Ordering::Relaxed)).sum()
+    }
+
+    /// Number of records dropped by the level filter
+    pub fn suppressed_by_level(&self) -> u64 {
+        self.suppressed_by_level.load(Ordering::Relaxed)
+    }
+
+    /// Number of records dropped by the sampling rules
+    pub fn suppressed_by_sampling(&self) -> u64 {
+        self.suppressed_by_sampling.load(Ordering::Relaxed)
+    }
+
+    /// Number of writes to the configured output that failed
+    pub fn write_failures(&self) -> u64 {
+        self.write_failures.load(Ordering::Relaxed)
+    }
+
+    /// Number of lines written to the stderr fallback
+    pub fn fallback_writes(&self) -> u64 {
+        self.fallback_writes.load(Ordering::Relaxed)
+    }
+
+    /// Reset all counters to zero
+    pub fn reset(&self) {
+        for counter in &self.emitted {
+            counter.store(0, Ordering::Relaxed);
+        }
+        self.suppressed_by_level.store(0, Ordering::Relaxed);
+        self.suppressed_by_sampling.store(0, Ordering::Relaxed);
+        self.write_failures.store(0, Ordering::Relaxed);
+        self.fallback_writes.store(0, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_emitted(&self, level: Level) {
+        self.emitted[level as usize].fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_suppressed_by_level(&self) {
+        self.suppressed_by_level.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_suppressed_by_sampling(&self) {
+        self.suppressed_by_sampling.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_write_failure(&self) {
+        self.write_failures.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_fallback_write(&self) {
+        self.fallback_writes.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Get the global logger counters
+pub fn stats() -> &'static Stats {
+    &STATS
+}
+
+/// Whether `log` tracks the highest emitted level
+static TRACK_MAX_LEVEL: AtomicBool = AtomicBool::new(false);
+
+/// Highest emitted level as `level + 1` (0 = none)
+static MAX_OBSERVED: AtomicU8 = AtomicU8::new(0);
+
+/// Start tracking the highest level emitted (e.g. to pick a CLI exit code)
+pub fn track_max_observed_level() {
+    TRACK_MAX_LEVEL.store(true, Ordering::Relaxed);
+}
+
+/// Highest level emitted since tracking started (None if nothing was logged)
+///
+/// ```ignore
+/// if max_observed_level() >= Some(Level::Error) {
+///     std::process::exit(1);
+/// }
+/// ```
+pub fn max_observed_level() -> Option<Level> {
+    match MAX_OBSERVED.load(Ordering::Relaxed) {
+        0 => None,
+        value => Some(Level::from_u8(value - 1)),
+    }
+}
+
+/// Forget the highest observed level
+pub fn reset_max_observed_level() {
+    MAX_OBSERVED.store(0, Ordering::Relaxed);
+}
+
+/// Update the highest observed level, if tracking
+pub(crate) fn observe_level(level: Level) {
+    if TRACK_MAX_LEVEL.load(Ordering::Relaxed) {
+        MAX_OBSERVED.fetch_max(level as u8 + 1, Ordering::Relaxed);
+    }
+}
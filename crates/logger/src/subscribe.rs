@@ -0,0 +1,42 @@
+use crate::record::{OwnedRecord, Record};
+use crate::utils::lock;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
+use std::sync::Mutex;
+
+/// Records buffered per subscriber before new ones are dropped
+pub const SUBSCRIBER_CAPACITY: usize = 1024;
+
+/// Senders of the live subscribers
+static SUBSCRIBERS: Mutex<Vec<SyncSender<OwnedRecord>>> = Mutex::new(Vec::new());
+
+/// Number of live subscribers (skips the lock when zero)
+static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// Receive every record emitted from now on
+///
+/// Each subscriber gets its own copy. A subscriber that falls more than
+/// `SUBSCRIBER_CAPACITY` records behind misses records rather than slowing
+/// down logging; dropping the receiver unsubscribes.
+pub fn subscribe() -> Receiver<OwnedRecord> {
+    let (sender, receiver) = mpsc::sync_channel(SUBSCRIBER_CAPACITY);
+    let mut subscribers = lock(&SUBSCRIBERS);
+    subscribers.push(sender);
+    COUNT.store(subscribers.len(), Ordering::Relaxed);
+    receiver
+}
+
+/// Send `record` to all subscribers, forgetting those that hung up
+pub(crate) fn publish(record: &Record) {
+    if COUNT.load(Ordering::Relaxed) == 0 {
+        return;
+    }
+
+    let owned = OwnedRecord::from(record);
+    let mut subscribers = lock(&SUBSCRIBERS);
+    subscribers.retain(|sender| match sender.try_send(owned.clone()) {
+        Ok(()) | Err(TrySendError::Full(_)) => true,
+        Err(TrySendError::Disconnected(_)) => false,
+    });
+    COUNT.store(subscribers.len(), Ordering::Relaxed);
+}
@@ -0,0 +1,73 @@
+use crate::utils::{read, write};
+use std::sync::RwLock;
+use std::thread::{self, Thread};
+
+/// How the current thread is shown in each line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThreadDisplay {
+    /// Thread name (`unnamed` for threads without one)
+    Name,
+    /// Numeric thread id
+    Id,
+    /// Thread name, falling back to the id for unnamed threads
+    NameOrId,
+}
+
+/// How numeric thread ids are rendered
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThreadIdFormat {
+    /// e.g. `12` (default)
+    Decimal,
+    /// e.g. `0xc`
+    Hex,
+}
+
+/// Global thread display (None = threads not shown)
+static SHOW_THREAD: RwLock<Option<ThreadDisplay>> = RwLock::new(None);
+
+/// Global thread id rendering
+static THREAD_ID_FORMAT: RwLock<ThreadIdFormat> = RwLock::new(ThreadIdFormat::Decimal);
+
+/// Show the logging thread as `[name]` after the timestamp (None to hide)
+pub fn set_show_thread(display: Option<ThreadDisplay>) {
+    *write(&SHOW_THREAD) = display;
+}
+
+/// Set how numeric thread ids are rendered
+pub fn set_thread_id_format(format: ThreadIdFormat) {
+    *write(&THREAD_ID_FORMAT) = format;
+}
+
+/// Label for the current thread, if threads are shown
+pub(crate) fn thread_label() -> Option<String> {
+    let display = (*read(&SHOW_THREAD))?;
+    let current = thread::current();
+    let label = match (display, current.name()) {
+        (ThreadDisplay::Name, name) => name.unwrap_or("unnamed").to_string(),
+        (ThreadDisplay::NameOrId, Some(name)) => name.to_string(),
+        (ThreadDisplay::Id | ThreadDisplay::NameOrId, _) => format_id(thread_id(&current)),
+    };
+    Some(label)
+}
+
+/// Numeric id of a thread
+///
+/// `ThreadId::as_u64` is unstable, so the number is parsed from the stable
+/// `ThreadId(N)` debug output.
+fn thread_id(thread: &Thread) -> u64 {
+    let debug = format!("{:?}", thread.id());
+    debug
+        .chars()
+        .filter(char::is_ascii_digit)
+        .collect::<String>()
+        .parse()
+        .unwrap_or(0)
+}
+
+/// Render a thread id in the configured format
+fn format_id(id: u64) -> String {
+    match *read(&THREAD_ID_FORMAT) {
+        ThreadIdFormat::Decimal => id.to_string(),
+        ThreadIdFormat::Hex => format!("{id:#x}"),
+    }
+}
@@ -0,0 +1,58 @@
+use crate::level::Level;
+use crate::loggers::log;
+use crate::record::{Field, Record};
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+/// Guard that logs the elapsed time since its creation when dropped
+///
+/// ```ignore
+/// let _timer = Timer::new("load config").level(Level::Info);
+/// ```
+pub struct Timer {
+    label: String,
+    level: Level,
+    start: Instant,
+    fields: Vec<(String, String)>,
+}
+
+impl Timer {
+    /// Start a timer that logs `"<label> in <elapsed>"` at debug level
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            level: Level::Debug,
+            start: Instant::now(),
+            fields: Vec::new(),
+        }
+    }
+
+    /// Log at `level` instead of debug
+    pub fn level(mut self, level: Level) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Attach a structured field to the emitted record
+    pub fn field(mut self, key: &str, value: impl Display) -> Self {
+        self.fields.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Time since the timer was started
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+}
+
+impl Drop for Timer {
+    fn drop(&mut self) {
+        let message = format!("{} in {:.2?}", self.label, self.elapsed());
+        let fields: Vec<Field> = self
+            .fields
+            .iter()
+            .map(|(key, value)| (key.as_str(), value as &dyn Display))
+            .collect();
+        log(&Record::new(self.level, &message).with_fields(&fields));
+    }
+}
@@ -0,0 +1,72 @@
+use std::borrow::Cow;
+use std::fmt::Write;
+
+/// Escape bytes for display: valid printable UTF-8 passes through,
+/// backslashes become `\\` and control or invalid bytes become `\xNN`
+pub fn escape_bytes(input: &[u8]) -> String {
+    let mut result = String::with_capacity(input.len());
+
+    for chunk in input.utf8_chunks() {
+        for c in chunk.valid().chars() {
+            match c {
+                '\\' => result.push_str("\\\\"),
+                c if c.is_control() => {
+                    let mut buf = [0u8; 4];
+                    for byte in c.encode_utf8(&mut buf).bytes() {
+                        let _ = write!(result, "\\x{byte:02x}");
+                    }
+                }
+                c => result.push(c),
+            }
+        }
+        for byte in chunk.invalid() {
+            let _ = write!(result, "\\x{byte:02x}");
+        }
+    }
+
+    result
+}
+
+/// Escape control characters in `input`: `\n`, `\r`, `\t` and NUL become their
+/// escape sequences and any other control character (e.g. ESC) becomes `\xNN`
+pub fn escape_controls(input: &str) -> Cow<'_, str> {
+    if !input.chars().any(char::is_control) {
+        return Cow::Borrowed(input);
+    }
+
+    let mut result = String::with_capacity(input.len() + 8);
+    for c in input.chars() {
+        match c {
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            '\0' => result.push_str("\\0"),
+            c if c.is_control() => {
+                let mut buf = [0u8; 4];
+                for byte in c.encode_utf8(&mut buf).bytes() {
+                    let _ = write!(result, "\\x{byte:02x}");
+                }
+            }
+            c => result.push(c),
+        }
+    }
+    Cow::Owned(result)
+}
+
+/// Escape NUL in `input` as `\0`, leaving other characters untouched
+pub fn escape_nul(input: &str) -> Cow<'_, str> {
+    if !input.contains('\0') {
+        return Cow::Borrowed(input);
+    }
+
+    Cow::Owned(input.replace('\0', "\\0"))
+}
+
+/// Escape `\n` and `\r` in `input`, leaving other characters untouched
+pub fn escape_newlines(input: &str) -> Cow<'_, str> {
+    if !input.contains(['\n', '\r']) {
+        return Cow::Borrowed(input);
+    }
+
+    Cow::Owned(input.replace('\n', "\\n").replace('\r', "\\r"))
+}
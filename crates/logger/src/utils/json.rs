@@ -0,0 +1,33 @@
+use std::fmt::Write;
+
+/// Append `value` as a JSON number or boolean if it already is one, else as a string
+pub fn json_scalar(out: &mut String, value: &str) {
+    let is_int = value.parse::<i64>().is_ok_and(|n| n.to_string() == value);
+    let is_float = value
+        .parse::<f64>()
+        .is_ok_and(|n| n.is_finite() && n.to_string() == value);
+    if is_int || is_float || value == "true" || value == "false" {
+        out.push_str(value);
+    } else {
+        write_json_string(out, value);
+    }
+}
+
+/// Append `value` as a quoted JSON string
+pub fn write_json_string(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
@@ -1,3 +1,13 @@
 mod ansi;
+mod escape;
+mod json;
+mod sync;
+mod time;
+mod type_name;
 
 pub(crate) use ansi::strip_ansi;
+pub(crate) use escape::{escape_bytes, escape_controls, escape_newlines, escape_nul};
+pub(crate) use json::{json_scalar, write_json_string};
+pub(crate) use sync::{lock, read, write};
+pub(crate) use time::civil_from_days;
+pub(crate) use type_name::short_type_name;
@@ -0,0 +1,20 @@
+/// Strip module paths from a type name (`std::io::BufWriter<a::B>` -> `BufWriter<B>`)
+pub fn short_type_name(name: &str) -> String {
+    let mut result = String::with_capacity(name.len());
+    let mut segment_start = 0;
+    let mut chars = name.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == ':' && chars.peek() == Some(&':') {
+            chars.next();
+            result.truncate(segment_start);
+        } else {
+            result.push(c);
+            if !(c.is_alphanumeric() || c == '_') {
+                segment_start = result.len();
+            }
+        }
+    }
+
+    result
+}
@@ -0,0 +1,132 @@
+use crate::record::Record;
+use crate::writers::RecordWriter;
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+/// Writer that coalesces records into a single write to the inner writer
+///
+/// A batch is written once `max_records` lines are pending or the oldest
+/// pending line is older than `max_delay`. The delay is checked on every write
+/// and flush, so pair this with `set_flush_interval` to drain idle batches.
+/// Pending lines are written on `shutdown` and when the writer is dropped.
+///
+/// Each batch can be wrapped in a header and footer with lines joined by a
+/// separator, e.g. `[`, `]` and `,` for JSON arrays.
+pub struct BatchWriter<W: Write + Send> {
+    inner: W,
+    buffer: Vec<u8>,
+    pending: usize,
+    max_records: usize,
+    max_delay: Duration,
+    started: Option<Instant>,
+    header: Vec<u8>,
+    separator: Vec<u8>,
+    footer: Vec<u8>,
+}
+
+impl<W: Write + Send> BatchWriter<W> {
+    /// Batch up to `max_records` lines per write to `inner`
+    pub fn new(inner: W, max_records: usize) -> Self {
+        Self {
+            inner,
+            buffer: Vec::new(),
+            pending: 0,
+            max_records: max_records.max(1),
+            max_delay: Duration::from_secs(1),
+            started: None,
+            header: Vec::new(),
+            separator: Vec::new(),
+            footer: Vec::new(),
+        }
+    }
+
+    /// Write a partial batch once its oldest line is this old (default 1s)
+    pub fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Bytes written before the first line of each batch
+    pub fn header(mut self, header: &[u8]) -> Self {
+        self.header = header.to_vec();
+        self
+    }
+
+    /// Bytes written between lines of a batch
+    pub fn separator(mut self, separator: &[u8]) -> Self {
+        self.separator = separator.to_vec();
+        self
+    }
+
+    /// Bytes written after the last line of each batch
+    pub fn footer(mut self, footer: &[u8]) -> Self {
+        self.footer = footer.to_vec();
+        self
+    }
+
+    /// Number of lines waiting to be written
+    pub fn pending(&self) -> usize {
+        self.pending
+    }
+
+    /// Whether the pending batch should be written now
+    fn batch_due(&self) -> bool {
+        self.pending >= self.max_records
+            || self
+                .started
+                .is_some_and(|started| started.elapsed() >= self.max_delay)
+    }
+
+    /// Write all pending lines in one call
+    fn write_batch(&mut self) -> io::Result<()> {
+        if self.pending == 0 {
+            return Ok(());
+        }
+
+        self.buffer.extend_from_slice(&self.footer);
+        let result = self
+            .inner
+            .write_all(&self.buffer)
+            .and_then(|()| self.inner.flush());
+        self.buffer.clear();
+        self.pending = 0;
+        self.started = None;
+        result
+    }
+}
+
+impl<W: Write + Send> RecordWriter for BatchWriter<W> {
+    fn write_record(&mut self, _record: &Record, line: &[u8]) -> io::Result<()> {
+        if self.pending == 0 {
+            self.buffer.extend_from_slice(&self.header);
+            self.started = Some(Instant::now());
+        } else {
+            self.buffer.extend_from_slice(&self.separator);
+        }
+        self.buffer.extend_from_slice(line);
+        self.pending += 1;
+
+        if self.batch_due() {
+            self.write_batch()?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if self.batch_due() {
+            self.write_batch()?;
+        }
+        Ok(())
+    }
+
+    /// Write the pending batch, however small, and flush the inner writer
+    fn shutdown(&mut self, _timeout: Duration) -> bool {
+        self.write_batch().and_then(|()| self.inner.flush()).is_ok()
+    }
+}
+
+impl<W: Write + Send> Drop for BatchWriter<W> {
+    fn drop(&mut self) {
+        let _ = self.write_batch();
+    }
+}
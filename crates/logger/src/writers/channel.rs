@@ -0,0 +1,68 @@
+use crate::record::Record;
+use crate::utils::strip_ansi;
+use crate::writers::{OverflowPolicy, RecordWriter};
+use std::io;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{SyncSender, TrySendError};
+use std::sync::Arc;
+
+/// Writer that sends each formatted line (ANSI codes stripped) to a channel
+///
+/// Useful for custom pipelines that batch or ship lines themselves. Clones
+/// share the dropped-line counter, so keep one as a handle after `set_output`.
+#[derive(Clone)]
+pub struct ChannelWriter {
+    sender: SyncSender<String>,
+    policy: OverflowPolicy,
+    dropped: Arc<AtomicU64>,
+}
+
+impl ChannelWriter {
+    pub fn new(sender: SyncSender<String>) -> Self {
+        Self {
+            sender,
+            policy: OverflowPolicy::Block,
+            dropped: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Set what happens when the channel is full (default `Block`)
+    pub fn overflow_policy(mut self, policy: OverflowPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Number of lines dropped because the channel was full
+    pub fn dropped_count(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+}
+
+impl RecordWriter for ChannelWriter {
+    fn write_record(&mut self, _record: &Record, line: &[u8]) -> io::Result<()> {
+        let line = String::from_utf8_lossy(&strip_ansi(line)).into_owned();
+        let disconnected = match self.policy {
+            OverflowPolicy::Block => self.sender.send(line).is_err(),
+            OverflowPolicy::Drop => match self.sender.try_send(line) {
+                Ok(()) => false,
+                Err(TrySendError::Full(_)) => {
+                    self.dropped.fetch_add(1, Ordering::Relaxed);
+                    false
+                }
+                Err(TrySendError::Disconnected(_)) => true,
+            },
+        };
+
+        if disconnected {
+            return Err(io::Error::new(
+                io::ErrorKind::BrokenPipe,
+                "channel receiver dropped",
+            ));
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
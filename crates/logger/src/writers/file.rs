@@ -3,22 +3,33 @@ use std::fs::File;
 use std::io::{self, Write};
 use std::path::Path;
 
-/// Writer that outputs to a file (ANSI codes stripped)
+/// Writer that outputs to a file (ANSI codes stripped unless kept with `with_color`)
 pub struct FileWriter {
     file: File,
+    keep_ansi: bool,
 }
 
 impl FileWriter {
     pub fn new<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::with_color(path, false)
+    }
+
+    /// Create the file, keeping ANSI color codes if `keep_ansi` is set
+    ///
+    /// Useful for files read back with `cat` or `less -R`.
+    pub fn with_color<P: AsRef<Path>>(path: P, keep_ansi: bool) -> io::Result<Self> {
         let file = File::create(path)?;
-        Ok(Self { file })
+        Ok(Self { file, keep_ansi })
     }
 }
 
 impl Write for FileWriter {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        let clean = strip_ansi(buf);
-        self.file.write_all(&clean)?;
+        if self.keep_ansi {
+            self.file.write_all(buf)?;
+        } else {
+            self.file.write_all(&strip_ansi(buf))?;
+        }
         Ok(buf.len())
     }
 
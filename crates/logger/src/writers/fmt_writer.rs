@@ -0,0 +1,42 @@
+use crate::record::Record;
+use crate::writers::RecordWriter;
+use std::fmt;
+use std::io;
+
+/// Adapter writing log lines to a `fmt::Write` (e.g. a `String` or a UI buffer)
+///
+/// Lines are passed through unchanged, ANSI codes included.
+#[derive(Debug, Clone, Default)]
+pub struct FmtWriter<W> {
+    inner: W,
+}
+
+impl<W: fmt::Write + Send> FmtWriter<W> {
+    pub fn new(inner: W) -> Self {
+        Self { inner }
+    }
+
+    /// Get a reference to the underlying writer
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Unwrap the underlying writer
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: fmt::Write + Send> RecordWriter for FmtWriter<W> {
+    fn write_record(&mut self, _record: &Record, line: &[u8]) -> io::Result<()> {
+        let line = std::str::from_utf8(line)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        self.inner
+            .write_str(line)
+            .map_err(|_| io::Error::other("fmt::Write sink failed"))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+use crate::level::Level;
+use crate::record::Record;
+use crate::writers::oversized::fit_message;
+use crate::writers::RecordWriter;
+use std::io;
+use std::os::unix::net::UnixDatagram;
+use std::path::{Path, PathBuf};
+
+/// Default path of the journal's native protocol socket
+const JOURNAL_SOCKET: &str = "/run/systemd/journal/socket";
+
+/// Default largest datagram sent to the journal
+const MAX_PAYLOAD: usize = 128 * 1024;
+
+/// Writer that sends records to the systemd journal with structured fields
+///
+/// Fields are sent as upper-case journal keys, so they can be queried with
+/// `journalctl FIELD=value`. Payloads over `max_payload` bytes are handled by
+/// `set_oversized_policy`; split pieces repeat every field but the message.
+pub struct JournaldWriter {
+    socket: UnixDatagram,
+    path: PathBuf,
+    max_payload: usize,
+}
+
+impl JournaldWriter {
+    pub fn new() -> io::Result<Self> {
+        Self::with_socket(JOURNAL_SOCKET)
+    }
+
+    /// Send to a custom socket path (useful for testing)
+    pub fn with_socket<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(Self {
+            socket: UnixDatagram::unbound()?,
+            path: path.as_ref().to_path_buf(),
+            max_payload: MAX_PAYLOAD,
+        })
+    }
+
+    /// Set the largest datagram to send (default 128 KiB)
+    pub fn max_payload(mut self, size: usize) -> Self {
+        self.max_payload = size;
+        self
+    }
+
+    /// Encode a record in the journal's native datagram format
+    pub fn payload(record: &Record) -> Vec<u8> {
+        let mut payload = Vec::new();
+        push_field(
+            &mut payload,
+            "PRIORITY",
+            &priority(record.level()).to_string(),
+        );
+        push_field(&mut payload, "MESSAGE", record.message());
+        if let Some(file) = record.file() {
+            push_field(&mut payload, "CODE_FILE", file);
+        }
+        if let Some(line) = record.line() {
+            push_field(&mut payload, "CODE_LINE", &line.to_string());
+        }
+        for (key, value) in record.fields() {
+            push_field(&mut payload, &field_name(key), &value.to_string());
+        }
+        payload
+    }
+}
+
+impl RecordWriter for JournaldWriter {
+    fn write_record(&mut self, record: &Record, _line: &[u8]) -> io::Result<()> {
+        let payload = Self::payload(record);
+        if payload.len() <= self.max_payload {
+            self.socket.send_to(&payload, &self.path)?;
+            return Ok(());
+        }
+
+        let overhead = payload.len() - record.message().len();
+        for piece in fit_message(record.message(), self.max_payload.saturating_sub(overhead)) {
+            self.socket
+                .send_to(&Self::payload(&record.with_message(&piece)), &self.path)?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Syslog priority for a level
+fn priority(level: Level) -> u8 {
+    match level {
+        Level::Debug | Level::Off => 7,
+        Level::Info => 6,
+        Level::Warn => 4,
+        Level::Error => 3,
+    }
+}
+
+/// Convert a field key to a valid journal field name (`A-Z`, `0-9`, `_`)
+fn field_name(key: &str) -> String {
+    let name: String = key
+        .chars()
+        .map(|c| match c {
+            'a'..='z' => c.to_ascii_uppercase(),
+            'A'..='Z' | '0'..='9' => c,
+            _ => '_',
+        })
+        .collect();
+    let name = name.trim_start_matches('_');
+
+    match name.chars().next() {
+        Some(c) if !c.is_ascii_digit() => name.to_string(),
+        _ => format!("F_{name}"),
+    }
+}
+
+/// Append `KEY=value\n`, or the length-prefixed form for multi-line values
+fn push_field(payload: &mut Vec<u8>, key: &str, value: &str) {
+    payload.extend_from_slice(key.as_bytes());
+    if value.contains('\n') {
+        payload.push(b'\n');
+        payload.extend_from_slice(&(value.len() as u64).to_le_bytes());
+    } else {
+        payload.push(b'=');
+    }
+    payload.extend_from_slice(value.as_bytes());
+    payload.push(b'\n');
+}
@@ -0,0 +1,38 @@
+use crate::level::Level;
+use crate::record::Record;
+use crate::writers::RecordWriter;
+use std::io;
+use std::time::Duration;
+
+/// Writer that only forwards records at or above a minimum level
+///
+/// Useful inside a `TeeWriter` to give each branch its own level, e.g. a
+/// quiet console and a verbose file. The global level filters still apply
+/// first.
+pub struct LeveledWriter<W> {
+    inner: W,
+    min_level: Level,
+}
+
+impl<W: RecordWriter> LeveledWriter<W> {
+    pub fn new(inner: W, min_level: Level) -> Self {
+        Self { inner, min_level }
+    }
+}
+
+impl<W: RecordWriter> RecordWriter for LeveledWriter<W> {
+    fn write_record(&mut self, record: &Record, line: &[u8]) -> io::Result<()> {
+        if record.level() < self.min_level {
+            return Ok(());
+        }
+        self.inner.write_record(record, line)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+
+    fn shutdown(&mut self, timeout: Duration) -> bool {
+        self.inner.shutdown(timeout)
+    }
+}
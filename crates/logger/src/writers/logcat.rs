@@ -0,0 +1,74 @@
+use crate::level::Level;
+use crate::record::Record;
+use crate::writers::RecordWriter;
+use std::ffi::{c_char, c_int, CString};
+use std::fmt::Write;
+use std::io;
+
+#[link(name = "log")]
+extern "C" {
+    fn __android_log_write(prio: c_int, tag: *const c_char, text: *const c_char) -> c_int;
+}
+
+/// Android log priorities (`android/log.h`)
+const ANDROID_LOG_DEBUG: c_int = 3;
+const ANDROID_LOG_INFO: c_int = 4;
+const ANDROID_LOG_WARN: c_int = 5;
+const ANDROID_LOG_ERROR: c_int = 6;
+
+/// Writer that sends records to the Android log, shown by `logcat`
+///
+/// logcat adds its own time and priority, so only the message and fields
+/// are sent, as `message key=value ...`.
+pub struct LogcatWriter {
+    tag: CString,
+}
+
+impl LogcatWriter {
+    /// Log under `tag` (the logcat tag column)
+    pub fn new(tag: &str) -> Self {
+        Self { tag: c_string(tag) }
+    }
+
+    /// Android log priority for a level
+    pub fn priority(level: Level) -> i32 {
+        match level {
+            Level::Debug | Level::Off => ANDROID_LOG_DEBUG,
+            Level::Info => ANDROID_LOG_INFO,
+            Level::Warn => ANDROID_LOG_WARN,
+            Level::Error => ANDROID_LOG_ERROR,
+        }
+    }
+}
+
+impl RecordWriter for LogcatWriter {
+    fn write_record(&mut self, record: &Record, _line: &[u8]) -> io::Result<()> {
+        let mut text = record.message().to_string();
+        for (key, value) in record.fields() {
+            let _ = write!(text, " {key}={value}");
+        }
+        let text = c_string(&text);
+
+        // SAFETY: both pointers are valid NUL-terminated strings for the call
+        let result = unsafe {
+            __android_log_write(
+                Self::priority(record.level()),
+                self.tag.as_ptr(),
+                text.as_ptr(),
+            )
+        };
+        if result < 0 {
+            return Err(io::Error::from_raw_os_error(-result));
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Convert to a C string, escaping interior NULs
+fn c_string(text: &str) -> CString {
+    CString::new(text.replace('\0', "\\0")).unwrap_or_default()
+}
@@ -1,12 +1,14 @@
-use crate::utils::strip_ansi;
+use crate::level::Level;
+use crate::utils::{lock, strip_ansi};
 use std::io::{self, Write};
 use std::sync::{Arc, Mutex};
 
-/// Writer that stores output in memory (ANSI codes stripped)
+/// Writer that stores output in memory (ANSI codes stripped unless kept with `with_color`)
 /// Useful for testing
 #[derive(Clone, Default)]
 pub struct MemoryWriter {
     buffer: Arc<Mutex<Vec<u8>>>,
+    keep_ansi: bool,
 }
 
 impl MemoryWriter {
@@ -14,15 +16,36 @@ impl MemoryWriter {
         Self::default()
     }
 
+    /// Create a writer that keeps ANSI color codes, e.g. to test colored output
+    pub fn with_color() -> Self {
+        Self {
+            keep_ansi: true,
+            ..Self::default()
+        }
+    }
+
     /// Get the contents as a string
     pub fn contents(&self) -> String {
-        let data = self.buffer.lock().unwrap();
+        let data = lock(&self.buffer);
         String::from_utf8_lossy(&data).to_string()
     }
 
+    /// Captured lines with their parsed level (None if unrecognized)
+    ///
+    /// Any kept ANSI codes are stripped before parsing.
+    ///
+    /// ```ignore
+    /// assert!(w.iter_lines().any(|(l, m)| l == Some(Level::Error) && m.contains("boom")));
+    /// ```
+    pub fn iter_lines(&self) -> impl Iterator<Item = (Option<Level>, String)> {
+        let contents = String::from_utf8_lossy(&strip_ansi(&lock(&self.buffer))).to_string();
+        let lines: Vec<_> = contents.lines().map(parse_line).collect();
+        lines.into_iter()
+    }
+
     /// Clear the buffer
     pub fn clear(&self) {
-        self.buffer.lock().unwrap().clear();
+        lock(&self.buffer).clear();
     }
 
     /// Get a boxed writer for use with set_output
@@ -33,8 +56,11 @@ impl MemoryWriter {
 
 impl Write for MemoryWriter {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        let clean = strip_ansi(buf);
-        self.buffer.lock().unwrap().extend_from_slice(&clean);
+        if self.keep_ansi {
+            lock(&self.buffer).extend_from_slice(buf);
+        } else {
+            lock(&self.buffer).extend_from_slice(&strip_ansi(buf));
+        }
         Ok(buf.len())
     }
 
@@ -42,3 +68,30 @@ impl Write for MemoryWriter {
         Ok(())
     }
 }
+
+/// Split a line into its level and the text after it
+///
+/// The level is looked for in the first few tokens, so timestamps, sequence
+/// numbers and thread labels before it are skipped.
+fn parse_line(line: &str) -> (Option<Level>, String) {
+    let mut rest = line;
+    for _ in 0..4 {
+        let (token, tail) = rest.split_once(' ').unwrap_or((rest, ""));
+        if let Some(level) = parse_level(token) {
+            return (Some(level), tail.to_string());
+        }
+        rest = tail.trim_start();
+        if rest.is_empty() {
+            break;
+        }
+    }
+    (None, line.to_string())
+}
+
+/// Parse a level token such as `errr`, `ERROR` or `[warn]`
+fn parse_level(token: &str) -> Option<Level> {
+    let token = token.trim_start_matches('[').trim_end_matches(']');
+    Level::from_label(token)
+        .or_else(|| token.parse().ok())
+        .filter(|level| *level != Level::Off)
+}
@@ -1,7 +1,53 @@
+mod batch;
+mod channel;
 mod file;
+mod fmt_writer;
+#[cfg(all(feature = "journald", unix))]
+mod journald;
+mod leveled;
+#[cfg(all(feature = "android", target_os = "android"))]
+mod logcat;
 mod memory;
+mod non_blocking;
+mod null;
+#[cfg(all(feature = "apple", target_vendor = "apple"))]
+mod os_log;
+#[cfg(feature = "otel")]
+mod otlp;
+mod oversized;
+mod prefix;
+mod record_writer;
+mod ring;
+mod rolling;
+mod sharded;
 mod stdout;
+mod tee;
+mod test;
+mod udp;
 
+pub use batch::BatchWriter;
+pub use channel::ChannelWriter;
 pub use file::FileWriter;
+pub use fmt_writer::FmtWriter;
+#[cfg(all(feature = "journald", unix))]
+pub use journald::JournaldWriter;
+pub use leveled::LeveledWriter;
+#[cfg(all(feature = "android", target_os = "android"))]
+pub use logcat::LogcatWriter;
 pub use memory::MemoryWriter;
+pub use non_blocking::{NonBlockingWriter, OverflowPolicy};
+pub use null::NullWriter;
+#[cfg(all(feature = "apple", target_vendor = "apple"))]
+pub use os_log::{OsLogPrivacy, OsLogWriter};
+#[cfg(feature = "otel")]
+pub use otlp::OtlpWriter;
+pub use oversized::{set_oversized_policy, OversizedPolicy};
+pub use prefix::PrefixWriter;
+pub use record_writer::RecordWriter;
+pub use ring::{RingBufferWriter, RingEntry};
+pub use rolling::{RollInterval, RollNaming, RollingFileWriter};
+pub use sharded::LevelShardedFileWriter;
 pub use stdout::StdoutWriter;
+pub use tee::TeeWriter;
+pub use test::TestWriter;
+pub use udp::UdpWriter;
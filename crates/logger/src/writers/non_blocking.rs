@@ -0,0 +1,298 @@
+use crate::config::format_line;
+use crate::level::Level;
+use crate::record::Record;
+use crate::utils::lock;
+use crate::writers::RecordWriter;
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, SyncSender, TrySendError};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// What a `NonBlockingWriter` does when its queue is full
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// Drop the new line and count it (default)
+    #[default]
+    Drop,
+    /// Wait for the background thread to make room
+    Block,
+}
+
+/// Callback invoked with the total dropped count after lines are dropped
+type OverflowCallback = Box<dyn Fn(u64) + Send + Sync>;
+
+/// Overflow callback as shared with the background thread
+type SharedCallback = Arc<dyn Fn(u64) + Send + Sync>;
+
+/// Message sent to the background thread
+enum Message {
+    Line(Vec<u8>),
+    Drain(SyncSender<()>),
+    Shutdown(SyncSender<()>),
+}
+
+/// Output owned by the background thread
+type Inner = Box<dyn Write + Send>;
+
+/// State shared between clones of a `NonBlockingWriter`
+struct Shared {
+    dropped: AtomicU64,
+    unreported: AtomicU64,
+    report_interval: Mutex<Duration>,
+    callback: Mutex<Option<SharedCallback>>,
+    /// Set by `shutdown`; later lines bypass the queue
+    closed: AtomicBool,
+    /// Background thread, returning the inner writer when it exits
+    worker: Mutex<Option<JoinHandle<Inner>>>,
+    /// Inner writer taken back from the thread after a shutdown
+    direct: Mutex<Option<Inner>>,
+}
+
+/// Writer that hands lines to a background thread through a bounded queue
+///
+/// Clones share the queue and counters, so keep one as a handle after
+/// `set_output`. After lines are dropped, the background thread writes a
+/// synthetic warning such as `dropped 12 log records due to backpressure`
+/// straight to the inner writer, at most once per report interval. Drops not
+/// yet reported are written by `drain` and `shutdown`, so the loss is always
+/// made visible.
+///
+/// After `shutdown`, lines are written directly to the inner writer (or to
+/// stderr if the thread did not finish in time).
+#[derive(Clone)]
+pub struct NonBlockingWriter {
+    sender: SyncSender<Message>,
+    policy: OverflowPolicy,
+    shared: Arc<Shared>,
+}
+
+impl NonBlockingWriter {
+    /// Write to `inner` from a background thread, queueing up to `capacity` lines
+    pub fn new<W: Write + Send + 'static>(inner: W, capacity: usize) -> Self {
+        let (sender, receiver) = mpsc::sync_channel(capacity);
+        let shared = Arc::new(Shared {
+            dropped: AtomicU64::new(0),
+            unreported: AtomicU64::new(0),
+            report_interval: Mutex::new(Duration::from_secs(1)),
+            callback: Mutex::new(None),
+            closed: AtomicBool::new(false),
+            worker: Mutex::new(None),
+            direct: Mutex::new(None),
+        });
+        let worker = {
+            let shared = Arc::clone(&shared);
+            thread::spawn(move || run(Box::new(inner), receiver, &shared))
+        };
+        *lock(&shared.worker) = Some(worker);
+
+        Self {
+            sender,
+            policy: OverflowPolicy::Drop,
+            shared,
+        }
+    }
+
+    /// Set what happens when the queue is full
+    pub fn overflow_policy(mut self, policy: OverflowPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Minimum time between synthetic "dropped" warnings (default 1s)
+    pub fn report_interval(self, interval: Duration) -> Self {
+        *lock(&self.shared.report_interval) = interval;
+        self
+    }
+
+    /// Total number of lines dropped because the queue was full
+    pub fn dropped_count(&self) -> u64 {
+        self.shared.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Call `callback` with the total dropped count after lines are dropped
+    ///
+    /// The callback runs on the background thread, outside any logger lock,
+    /// so it may log; several drops in a row may be reported in one call.
+    pub fn set_overflow_callback(&self, callback: OverflowCallback) {
+        *lock(&self.shared.callback) = Some(Arc::from(callback));
+    }
+
+    /// Block until every queued line and pending drop warning has been written
+    pub fn drain(&self) {
+        let (done, wait) = mpsc::sync_channel(1);
+        if self.sender.send(Message::Drain(done)).is_ok() {
+            let _ = wait.recv();
+        }
+    }
+
+    /// Stop the queue, write everything queued and join the background thread
+    ///
+    /// Returns whether the queue drained within `timeout`; if not, the
+    /// thread keeps writing in the background. Later lines are written
+    /// directly instead of queued.
+    pub fn shutdown(&self, timeout: Duration) -> bool {
+        if self.shared.closed.swap(true, Ordering::SeqCst) {
+            return lock(&self.shared.worker).is_none();
+        }
+
+        let deadline = Instant::now() + timeout;
+        let (done, wait) = mpsc::sync_channel(1);
+        let mut message = Message::Shutdown(done);
+        loop {
+            match self.sender.try_send(message) {
+                Ok(()) => break,
+                Err(TrySendError::Full(unsent)) if Instant::now() < deadline => {
+                    message = unsent;
+                    thread::sleep(Duration::from_millis(1));
+                }
+                Err(_) => return false,
+            }
+        }
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if wait.recv_timeout(remaining).is_err() {
+            return false;
+        }
+
+        if let Some(worker) = lock(&self.shared.worker).take() {
+            if let Ok(inner) = worker.join() {
+                *lock(&self.shared.direct) = Some(inner);
+            }
+        }
+        true
+    }
+
+    /// Write `line` without the queue, after a shutdown
+    fn write_direct(&self, line: &[u8]) -> io::Result<()> {
+        match lock(&self.shared.direct).as_mut() {
+            Some(inner) => inner.write_all(line).and_then(|()| Write::flush(inner)),
+            None => io::stderr().write_all(line),
+        }
+    }
+
+    /// Count a dropped line, for the background thread to report
+    fn record_dropped(&self) {
+        self.shared.dropped.fetch_add(1, Ordering::Relaxed);
+        self.shared.unreported.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+impl RecordWriter for NonBlockingWriter {
+    fn write_record(&mut self, _record: &Record, line: &[u8]) -> io::Result<()> {
+        if self.shared.closed.load(Ordering::SeqCst) {
+            return self.write_direct(line);
+        }
+
+        let message = Message::Line(line.to_vec());
+        let result = match self.policy {
+            OverflowPolicy::Block => self.sender.send(message).map_err(|_| ()),
+            OverflowPolicy::Drop => match self.sender.try_send(message) {
+                Err(TrySendError::Full(_)) => {
+                    self.record_dropped();
+                    Ok(())
+                }
+                result => result.map_err(|_| ()),
+            },
+        };
+        result.map_err(|()| io::Error::new(io::ErrorKind::BrokenPipe, "writer thread exited"))
+    }
+
+    /// Lines are flushed by the background thread; see `drain` to wait for them
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&mut self, timeout: Duration) -> bool {
+        NonBlockingWriter::shutdown(self, timeout)
+    }
+}
+
+/// Background loop: write lines until shut down or every sender is dropped
+fn run(mut inner: Inner, receiver: Receiver<Message>, shared: &Shared) -> Inner {
+    let mut reporter = Reporter::default();
+    loop {
+        // Wake up for a pending drop warning even when no lines arrive
+        let message = if shared.unreported.load(Ordering::Relaxed) > 0 {
+            match receiver.recv_timeout(reporter.until_due(shared)) {
+                Ok(message) => Some(message),
+                Err(RecvTimeoutError::Timeout) => None,
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+        } else {
+            match receiver.recv() {
+                Ok(message) => Some(message),
+                Err(_) => break,
+            }
+        };
+
+        match message {
+            Some(Message::Line(line)) => {
+                let _ = inner
+                    .write_all(&line)
+                    .and_then(|()| Write::flush(&mut inner));
+            }
+            Some(Message::Drain(done)) => {
+                reporter.report(&mut inner, shared, true);
+                let _ = done.send(());
+                continue;
+            }
+            Some(Message::Shutdown(done)) => {
+                reporter.report(&mut inner, shared, true);
+                let _ = Write::flush(&mut inner);
+                let _ = done.send(());
+                return inner;
+            }
+            None => {}
+        }
+        reporter.report(&mut inner, shared, false);
+    }
+    reporter.report(&mut inner, shared, true);
+    inner
+}
+
+/// Drop reporting state of the background thread
+#[derive(Default)]
+struct Reporter {
+    /// When the last warning was written (None = never, so the first is immediate)
+    last_report: Option<Instant>,
+    /// Dropped total last passed to the overflow callback
+    notified: u64,
+}
+
+impl Reporter {
+    /// Time left until the next warning may be written
+    fn until_due(&self, shared: &Shared) -> Duration {
+        let interval = *lock(&shared.report_interval);
+        self.last_report.map_or(Duration::ZERO, |last| {
+            interval.saturating_sub(last.elapsed())
+        })
+    }
+
+    /// Notify the callback of new drops and write a warning if one is due (or `force`d)
+    fn report(&mut self, inner: &mut Inner, shared: &Shared, force: bool) {
+        let total = shared.dropped.load(Ordering::Relaxed);
+        if total != self.notified {
+            self.notified = total;
+            let callback = lock(&shared.callback).clone();
+            if let Some(callback) = callback {
+                callback(total);
+            }
+        }
+
+        if !force && !self.until_due(shared).is_zero() {
+            return;
+        }
+        let count = shared.unreported.swap(0, Ordering::Relaxed);
+        if count == 0 {
+            return;
+        }
+
+        let message = format!("dropped {count} log records due to backpressure");
+        let line = format_line(&Record::new(Level::Warn, &message));
+        let _ = inner
+            .write_all(line.as_bytes())
+            .and_then(|()| Write::flush(inner));
+        self.last_report = Some(Instant::now());
+    }
+}
@@ -0,0 +1,20 @@
+use crate::record::Record;
+use crate::writers::RecordWriter;
+use std::io;
+
+/// Writer that discards all output
+///
+/// Lines are still formatted; use `set_max_level(Level::Off)` or the
+/// `max_level_off` feature to skip formatting as well.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NullWriter;
+
+impl RecordWriter for NullWriter {
+    fn write_record(&mut self, _record: &Record, _line: &[u8]) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
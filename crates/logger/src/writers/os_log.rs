@@ -0,0 +1,126 @@
+use crate::level::Level;
+use crate::record::Record;
+use crate::writers::RecordWriter;
+use std::ffi::{c_char, c_void, CString};
+use std::fmt::Write;
+use std::io;
+
+/// Opaque `os_log_t` handle
+type OsLog = *mut c_void;
+
+extern "C" {
+    /// Image handle of this binary, required by `_os_log_impl`
+    static __dso_handle: c_void;
+
+    fn os_log_create(subsystem: *const c_char, category: *const c_char) -> OsLog;
+
+    /// Backend of the `os_log` C macro, taking a pre-encoded argument buffer
+    fn _os_log_impl(
+        dso: *const c_void,
+        log: OsLog,
+        log_type: u8,
+        format: *const c_char,
+        buf: *const u8,
+        size: u32,
+    );
+}
+
+/// `os_log_type_t` values (`os/log.h`)
+const OS_LOG_TYPE_DEFAULT: u8 = 0x00;
+const OS_LOG_TYPE_INFO: u8 = 0x01;
+const OS_LOG_TYPE_DEBUG: u8 = 0x02;
+const OS_LOG_TYPE_ERROR: u8 = 0x10;
+
+/// Whether logged messages are redacted in Console.app and `log stream`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OsLogPrivacy {
+    /// Shown as `<private>` unless private data logging is enabled (default)
+    #[default]
+    Private,
+    /// Always shown
+    Public,
+}
+
+/// Writer that sends records to Apple's unified logging system (`os_log`)
+///
+/// Records appear in Console.app and `log stream` under the configured
+/// subsystem and category. Only the message and fields are sent, as
+/// `message key=value ...`; the system adds its own time and type.
+pub struct OsLogWriter {
+    log: OsLog,
+    privacy: OsLogPrivacy,
+}
+
+// SAFETY: os_log handles are thread-safe and live for the whole process
+unsafe impl Send for OsLogWriter {}
+
+impl OsLogWriter {
+    /// Log under `subsystem` (e.g. `com.example.app`) and `category`
+    pub fn new(subsystem: &str, category: &str) -> Self {
+        let subsystem = c_string(subsystem);
+        let category = c_string(category);
+        // SAFETY: both pointers are valid NUL-terminated strings for the call
+        let log = unsafe { os_log_create(subsystem.as_ptr(), category.as_ptr()) };
+        Self {
+            log,
+            privacy: OsLogPrivacy::Private,
+        }
+    }
+
+    /// Set whether messages are logged as public or private (default private)
+    pub fn privacy(mut self, privacy: OsLogPrivacy) -> Self {
+        self.privacy = privacy;
+        self
+    }
+
+    /// `os_log_type_t` for a level
+    pub fn log_type(level: Level) -> u8 {
+        match level {
+            Level::Debug | Level::Off => OS_LOG_TYPE_DEBUG,
+            Level::Info => OS_LOG_TYPE_INFO,
+            Level::Warn => OS_LOG_TYPE_DEFAULT,
+            Level::Error => OS_LOG_TYPE_ERROR,
+        }
+    }
+}
+
+impl RecordWriter for OsLogWriter {
+    fn write_record(&mut self, record: &Record, _line: &[u8]) -> io::Result<()> {
+        let mut text = record.message().to_string();
+        for (key, value) in record.fields() {
+            let _ = write!(text, " {key}={value}");
+        }
+        let text = c_string(&text);
+
+        // Argument buffer as the compiler encodes it for a single `%s`:
+        // summary flags, argument count, then descriptor, size and pointer
+        let (format, summary, descriptor) = match self.privacy {
+            OsLogPrivacy::Public => (c"%{public}s", 0x02, 0x22),
+            OsLogPrivacy::Private => (c"%{private}s", 0x03, 0x21),
+        };
+        let mut buf = vec![summary, 1, descriptor, size_of::<usize>() as u8];
+        buf.extend_from_slice(&(text.as_ptr() as usize).to_ne_bytes());
+
+        // SAFETY: `text` outlives the call and `buf` matches `format`
+        unsafe {
+            _os_log_impl(
+                &__dso_handle,
+                self.log,
+                Self::log_type(record.level()),
+                format.as_ptr(),
+                buf.as_ptr(),
+                buf.len() as u32,
+            );
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Convert to a C string, escaping interior NULs
+fn c_string(text: &str) -> CString {
+    CString::new(text.replace('\0', "\\0")).unwrap_or_default()
+}
@@ -0,0 +1,379 @@
+use crate::clock;
+use crate::failures::internal_error;
+use crate::level::Level;
+use crate::record::Record;
+use crate::utils::write_json_string;
+use crate::writers::RecordWriter;
+use std::fmt::Write as _;
+use std::io::{self, Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant, SystemTime};
+
+/// Writer that exports records as OTLP log records over HTTP/JSON
+///
+/// Records are batched and sent once `batch_size` records are pending or the
+/// oldest pending record is older than `batch_timeout`. The timeout is checked
+/// on every write and flush, so pair this with `set_flush_interval` to export
+/// idle batches. Only plain `http://` endpoints are supported.
+///
+/// Batches are posted from a background thread, so a slow collector never
+/// blocks logging. A failed export is retried with the next batch; at most
+/// `max_pending` records are kept meanwhile, the oldest being dropped first.
+/// `shutdown` (or dropping the writer) sends what is pending.
+pub struct OtlpWriter {
+    host: String,
+    path: String,
+    service_name: String,
+    timeout: Duration,
+    sender: Option<SyncSender<Export>>,
+    worker: Option<JoinHandle<()>>,
+    /// Set by `shutdown`; later records are discarded
+    closed: bool,
+    dropped: Arc<AtomicU64>,
+    batch: Vec<String>,
+    batch_size: usize,
+    batch_timeout: Duration,
+    batch_started: Option<Instant>,
+    max_pending: usize,
+}
+
+/// Message sent to the export thread
+enum Export {
+    Batch(Vec<String>),
+    Shutdown(Instant, SyncSender<bool>),
+}
+
+/// Collector connection, owned by the export thread
+struct Exporter {
+    host: String,
+    path: String,
+    service_name: String,
+    timeout: Duration,
+}
+
+impl OtlpWriter {
+    /// Export to a collector endpoint such as `http://localhost:4318/v1/logs`
+    pub fn new(endpoint: &str) -> io::Result<Self> {
+        let rest = endpoint.strip_prefix("http://").ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "endpoint must start with http://",
+            )
+        })?;
+        let (host, path) = match rest.find('/') {
+            Some(index) => (&rest[..index], &rest[index..]),
+            None => (rest, "/v1/logs"),
+        };
+
+        Ok(Self {
+            host: host.to_string(),
+            path: path.to_string(),
+            service_name: "unknown_service".to_string(),
+            timeout: Duration::from_secs(5),
+            sender: None,
+            worker: None,
+            closed: false,
+            dropped: Arc::new(AtomicU64::new(0)),
+            batch: Vec::new(),
+            batch_size: 512,
+            batch_timeout: Duration::from_secs(5),
+            batch_started: None,
+            max_pending: 8192,
+        })
+    }
+
+    /// Set the `service.name` resource attribute
+    pub fn service_name(mut self, name: &str) -> Self {
+        self.service_name = name.to_string();
+        self
+    }
+
+    /// Send once this many records are pending
+    pub fn batch_size(mut self, size: usize) -> Self {
+        self.batch_size = size.max(1);
+        self
+    }
+
+    /// Send once the oldest pending record is this old
+    pub fn batch_timeout(mut self, timeout: Duration) -> Self {
+        self.batch_timeout = timeout;
+        self
+    }
+
+    /// Connect, write and read timeout for each export request (default 5s)
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Most records kept while the collector is unreachable (default 8192)
+    pub fn max_pending(mut self, max: usize) -> Self {
+        self.max_pending = max.max(1);
+        self
+    }
+
+    /// Number of records dropped because too many were pending or the writer was shut down
+    pub fn dropped_count(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    /// OTLP severity number for a level (`Off` maps to unspecified)
+    pub fn severity_number(level: Level) -> u8 {
+        match level {
+            Level::Debug => 5,
+            Level::Info => 9,
+            Level::Warn => 13,
+            Level::Error => 17,
+            Level::Off => 0,
+        }
+    }
+
+    /// Encode one record as an OTLP `LogRecord` JSON object
+    pub fn encode(record: &Record, time: SystemTime) -> String {
+        let nanos = time
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        let level = record.level();
+
+        let mut out = String::new();
+        let _ = write!(
+            out,
+            "{{\"timeUnixNano\":\"{nanos}\",\"severityNumber\":{},\"severityText\":",
+            Self::severity_number(level)
+        );
+        write_json_string(&mut out, &level.to_string().to_uppercase());
+        out.push_str(",\"body\":{\"stringValue\":");
+        write_json_string(&mut out, record.message());
+        out.push_str("},\"attributes\":[");
+        for (index, (key, value)) in record.fields().iter().enumerate() {
+            if index > 0 {
+                out.push(',');
+            }
+            out.push_str("{\"key\":");
+            write_json_string(&mut out, key);
+            out.push_str(",\"value\":{\"stringValue\":");
+            write_json_string(&mut out, &value.to_string());
+            out.push_str("}}");
+        }
+        out.push_str("]}");
+        out
+    }
+
+    /// Wrap encoded log records in an OTLP `ExportLogsServiceRequest`
+    pub fn payload(&self, log_records: &[String]) -> String {
+        payload(&self.service_name, log_records)
+    }
+
+    /// Whether the pending batch should be sent now
+    fn batch_due(&self) -> bool {
+        self.batch.len() >= self.batch_size
+            || self
+                .batch_started
+                .is_some_and(|started| started.elapsed() >= self.batch_timeout)
+    }
+
+    /// Hand the pending batch to the export thread, starting it if needed
+    fn send_batch(&mut self) {
+        if self.batch.is_empty() {
+            return;
+        }
+        let Some(sender) = self.sender().cloned() else {
+            return;
+        };
+
+        let batch = std::mem::take(&mut self.batch);
+        match sender.try_send(Export::Batch(batch)) {
+            Ok(()) => self.batch_started = None,
+            // Keep the records until the thread catches up, dropping the oldest
+            Err(TrySendError::Full(Export::Batch(batch))) => {
+                self.batch = batch;
+                if let Some(excess) = self.batch.len().checked_sub(self.max_pending) {
+                    self.batch.drain(..excess);
+                    self.dropped.fetch_add(excess as u64, Ordering::Relaxed);
+                }
+            }
+            Err(_) => {}
+        }
+    }
+
+    /// Sender to the export thread, spawned on first use (None after shutdown)
+    fn sender(&mut self) -> Option<&SyncSender<Export>> {
+        if self.sender.is_none() && !self.closed {
+            let exporter = Exporter {
+                host: self.host.clone(),
+                path: self.path.clone(),
+                service_name: self.service_name.clone(),
+                timeout: self.timeout,
+            };
+            let (sender, receiver) = mpsc::sync_channel(4);
+            let (max_pending, dropped) = (self.max_pending, Arc::clone(&self.dropped));
+            self.worker = Some(thread::spawn(move || {
+                exporter.run(receiver, max_pending, &dropped)
+            }));
+            self.sender = Some(sender);
+        }
+        self.sender.as_ref()
+    }
+}
+
+fn payload(service_name: &str, log_records: &[String]) -> String {
+    let mut out = String::from(
+            "{\"resourceLogs\":[{\"resource\":{\"attributes\":[{\"key\":\"service.name\",\"value\":{\"stringValue\":",
+        );
+    write_json_string(&mut out, service_name);
+    out.push_str("}}]},\"scopeLogs\":[{\"scope\":{\"name\":\"rust-telemetry\"},\"logRecords\":[");
+    out.push_str(&log_records.join(","));
+    out.push_str("]}]}]}");
+    out
+}
+
+impl Exporter {
+    /// Export batches until shut down, retrying failed records with the next batch
+    fn run(&self, receiver: Receiver<Export>, max_pending: usize, dropped: &AtomicU64) {
+        let mut pending: Vec<String> = Vec::new();
+        let mut failing = false;
+        for message in receiver {
+            match message {
+                Export::Batch(batch) if batch.is_empty() => {}
+                Export::Batch(batch) => {
+                    pending.extend(batch);
+                    if let Some(excess) = pending.len().checked_sub(max_pending) {
+                        pending.drain(..excess);
+                        dropped.fetch_add(excess as u64, Ordering::Relaxed);
+                    }
+                    match self.post(&payload(&self.service_name, &pending)) {
+                        Ok(()) => {
+                            pending.clear();
+                            failing = false;
+                        }
+                        // Report once per outage rather than per batch
+                        Err(err) if !failing => {
+                            failing = true;
+                            internal_error(format_args!("OTLP export failed: {err}"));
+                        }
+                        Err(_) => {}
+                    }
+                }
+                Export::Shutdown(deadline, done) => {
+                    let sent = pending.is_empty()
+                        || (Instant::now() < deadline
+                            && self.post(&payload(&self.service_name, &pending)).is_ok());
+                    let _ = done.send(sent);
+                    return;
+                }
+            }
+        }
+    }
+
+    /// POST a JSON body to the collector and check the status code
+    fn post(&self, body: &str) -> io::Result<()> {
+        let mut stream = self.connect()?;
+        stream.set_read_timeout(Some(self.timeout))?;
+        stream.set_write_timeout(Some(self.timeout))?;
+        write!(
+            stream,
+            "POST {} HTTP/1.1\r\nHost: {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            self.path,
+            self.host,
+            body.len()
+        )?;
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response)?;
+        let status = response.split(' ').nth(1).unwrap_or_default();
+        if status.starts_with('2') {
+            Ok(())
+        } else {
+            Err(io::Error::other(format!(
+                "collector returned status {status:?}"
+            )))
+        }
+    }
+
+    /// Connect to the first reachable address of the host within the timeout
+    fn connect(&self) -> io::Result<TcpStream> {
+        let mut last_err = io::Error::new(io::ErrorKind::NotFound, "host has no address");
+        for addr in self.host.to_socket_addrs()? {
+            match TcpStream::connect_timeout(&addr, self.timeout) {
+                Ok(stream) => return Ok(stream),
+                Err(err) => last_err = err,
+            }
+        }
+        Err(last_err)
+    }
+}
+
+impl RecordWriter for OtlpWriter {
+    fn write_record(&mut self, record: &Record, _line: &[u8]) -> io::Result<()> {
+        if self.closed {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+            return Ok(());
+        }
+        let time = record
+            .time()
+            .map_or_else(clock::now, |time| SystemTime::UNIX_EPOCH + time);
+        self.batch.push(Self::encode(record, time));
+        self.batch_started.get_or_insert_with(Instant::now);
+        if self.batch_due() {
+            self.send_batch();
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if self.batch_due() {
+            self.send_batch();
+        }
+        Ok(())
+    }
+
+    /// Send every pending record, waiting at most `timeout` for the collector
+    fn shutdown(&mut self, timeout: Duration) -> bool {
+        let deadline = Instant::now() + timeout;
+        if self.closed || (self.sender.is_none() && self.batch.is_empty()) {
+            self.closed = true;
+            return true;
+        }
+        let batch = std::mem::take(&mut self.batch);
+        self.batch_started = None;
+        let Some(sender) = self.sender().cloned() else {
+            return batch.is_empty();
+        };
+        self.sender = None;
+        self.closed = true;
+
+        let (done, wait) = mpsc::sync_channel(1);
+        let mut messages = [Export::Batch(batch), Export::Shutdown(deadline, done)].into_iter();
+        let mut message = messages.next();
+        while let Some(unsent) = message.take() {
+            match sender.try_send(unsent) {
+                Ok(()) => message = messages.next(),
+                Err(TrySendError::Full(unsent)) if Instant::now() < deadline => {
+                    message = Some(unsent);
+                    thread::sleep(Duration::from_millis(1));
+                }
+                Err(_) => return false,
+            }
+        }
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        let sent = wait.recv_timeout(remaining).unwrap_or(false);
+        // A post still running past the deadline finishes on its own
+        if sent {
+            if let Some(worker) = self.worker.take() {
+                let _ = worker.join();
+            }
+        }
+        sent
+    }
+}
+
+impl Drop for OtlpWriter {
+    fn drop(&mut self) {
+        RecordWriter::shutdown(self, self.timeout);
+    }
+}
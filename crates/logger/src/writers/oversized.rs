@@ -0,0 +1,79 @@
+use crate::utils::{read, write};
+use std::borrow::Cow;
+use std::sync::RwLock;
+
+/// What transport-bounded writers do with a record too large to send
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OversizedPolicy {
+    /// Cut the message to fit and append `...[truncated]` (default)
+    #[default]
+    Truncate,
+    /// Send the message in several pieces, each with the full header and fields
+    Split,
+    /// Drop the record
+    Drop,
+}
+
+/// Marker appended to truncated messages
+const TRUNCATION_MARKER: &str = "...[truncated]";
+
+/// Smallest budget a message can be truncated into (the marker plus one byte)
+pub(crate) const MIN_MESSAGE_BUDGET: usize = TRUNCATION_MARKER.len() + 1;
+
+/// Global policy for oversized records
+static POLICY: RwLock<OversizedPolicy> = RwLock::new(OversizedPolicy::Truncate);
+
+/// Set how datagram writers (UDP, journald) handle records over their size limit
+pub fn set_oversized_policy(policy: OversizedPolicy) {
+    *write(&POLICY) = policy;
+}
+
+/// Fit `message` into `budget` bytes, returning the pieces to send
+///
+/// Returns the message unchanged if it fits, and nothing if it must be
+/// dropped (including when not even the marker fits).
+pub(crate) fn fit_message(message: &str, budget: usize) -> Vec<Cow<'_, str>> {
+    if message.len() <= budget {
+        return vec![Cow::Borrowed(message)];
+    }
+
+    match *read(&POLICY) {
+        OversizedPolicy::Drop => Vec::new(),
+        OversizedPolicy::Truncate if budget > TRUNCATION_MARKER.len() => {
+            let end = floor_char_boundary(message, budget - TRUNCATION_MARKER.len());
+            vec![Cow::Owned(format!(
+                "{}{TRUNCATION_MARKER}",
+                &message[..end]
+            ))]
+        }
+        OversizedPolicy::Truncate => Vec::new(),
+        OversizedPolicy::Split => split(message, budget)
+            .into_iter()
+            .map(Cow::Borrowed)
+            .collect(),
+    }
+}
+
+/// Split `message` into pieces of at most `budget` bytes on char boundaries
+fn split(message: &str, budget: usize) -> Vec<&str> {
+    let mut pieces = Vec::new();
+    let mut rest = message;
+    while !rest.is_empty() {
+        let end = floor_char_boundary(rest, budget);
+        if end == 0 {
+            return Vec::new();
+        }
+        pieces.push(&rest[..end]);
+        rest = &rest[end..];
+    }
+    pieces
+}
+
+/// Largest char boundary in `s` at or before `index`
+fn floor_char_boundary(s: &str, index: usize) -> usize {
+    let mut index = index.min(s.len());
+    while !s.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
@@ -0,0 +1,41 @@
+use crate::record::Record;
+use crate::writers::RecordWriter;
+use std::io;
+use std::time::Duration;
+
+/// Writer that prepends a fixed tag to every line before forwarding it
+///
+/// The prefix goes before the whole formatted line (timestamp included), and
+/// before each continuation line of multi-line records.
+pub struct PrefixWriter<W> {
+    inner: W,
+    prefix: String,
+}
+
+impl<W: RecordWriter> PrefixWriter<W> {
+    pub fn new(inner: W, prefix: &str) -> Self {
+        Self {
+            inner,
+            prefix: prefix.to_string(),
+        }
+    }
+}
+
+impl<W: RecordWriter> RecordWriter for PrefixWriter<W> {
+    fn write_record(&mut self, record: &Record, line: &[u8]) -> io::Result<()> {
+        let mut tagged = Vec::with_capacity(line.len() + self.prefix.len());
+        for part in line.split_inclusive(|&byte| byte == b'\n') {
+            tagged.extend_from_slice(self.prefix.as_bytes());
+            tagged.extend_from_slice(part);
+        }
+        self.inner.write_record(record, &tagged)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+
+    fn shutdown(&mut self, timeout: Duration) -> bool {
+        self.inner.shutdown(timeout)
+    }
+}
@@ -0,0 +1,48 @@
+use crate::record::Record;
+use std::io::{self, Write};
+use std::time::Duration;
+
+/// A log sink that receives the record alongside its formatted line
+///
+/// Every `io::Write` is a `RecordWriter` that simply writes the line, so any
+/// writer can be passed to `set_output`. Sinks with their own structure
+/// (e.g. journald) implement this trait directly to access the record.
+pub trait RecordWriter: Send {
+    /// Write the formatted `line` for `record`
+    fn write_record(&mut self, record: &Record, line: &[u8]) -> io::Result<()>;
+
+    /// Flush any buffered output
+    fn flush(&mut self) -> io::Result<()>;
+
+    /// Write everything pending and release background resources within `timeout`
+    ///
+    /// Returns whether all output was written in time. Writes after a
+    /// shutdown must still succeed. The default just flushes.
+    fn shutdown(&mut self, _timeout: Duration) -> bool {
+        self.flush().is_ok()
+    }
+}
+
+impl RecordWriter for Box<dyn RecordWriter> {
+    fn write_record(&mut self, record: &Record, line: &[u8]) -> io::Result<()> {
+        (**self).write_record(record, line)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        (**self).flush()
+    }
+
+    fn shutdown(&mut self, timeout: Duration) -> bool {
+        (**self).shutdown(timeout)
+    }
+}
+
+impl<W: Write + Send> RecordWriter for W {
+    fn write_record(&mut self, _record: &Record, line: &[u8]) -> io::Result<()> {
+        self.write_all(line)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Write::flush(self)
+    }
+}
@@ -0,0 +1,73 @@
+use crate::config::record_timestamp;
+use crate::level::Level;
+use crate::record::Record;
+use crate::utils::lock;
+use crate::writers::RecordWriter;
+use std::collections::VecDeque;
+use std::io;
+use std::sync::{Arc, Mutex};
+
+/// A log record retained by a `RingBufferWriter`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RingEntry {
+    /// Level of the record
+    pub level: Level,
+    /// Timestamp rendered in the configured timestamp format
+    pub time: String,
+    /// Message text
+    pub message: String,
+}
+
+/// Writer that retains the last N records as structured entries
+/// Useful for serving recent logs from an admin endpoint
+///
+/// Entries are taken from the record itself, so they do not depend on the
+/// configured format or timestamp position.
+#[derive(Clone)]
+pub struct RingBufferWriter {
+    capacity: usize,
+    entries: Arc<Mutex<VecDeque<RingEntry>>>,
+}
+
+impl RingBufferWriter {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Arc::new(Mutex::new(VecDeque::with_capacity(capacity))),
+        }
+    }
+
+    /// Get the retained entries, newest first
+    pub fn snapshot(&self) -> Vec<RingEntry> {
+        lock(&self.entries).iter().rev().cloned().collect()
+    }
+
+    /// Get a boxed writer for use with set_output
+    pub fn writer(&self) -> Box<dyn RecordWriter> {
+        Box::new(self.clone())
+    }
+}
+
+impl RecordWriter for RingBufferWriter {
+    fn write_record(&mut self, record: &Record, _line: &[u8]) -> io::Result<()> {
+        if self.capacity == 0 {
+            return Ok(());
+        }
+
+        let entry = RingEntry {
+            level: record.level(),
+            time: record_timestamp(record),
+            message: record.message().to_string(),
+        };
+        let mut entries = lock(&self.entries);
+        if entries.len() == self.capacity {
+            entries.pop_front();
+        }
+        entries.push_back(entry);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
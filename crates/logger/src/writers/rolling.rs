@@ -0,0 +1,305 @@
+use crate::clock;
+use crate::utils::strip_ansi;
+use std::collections::VecDeque;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Time window after which a rolling file is rotated
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RollInterval {
+    Hourly,
+    Daily,
+}
+
+impl RollInterval {
+    fn secs(&self) -> u64 {
+        match self {
+            RollInterval::Hourly => 3600,
+            RollInterval::Daily => 86400,
+        }
+    }
+}
+
+/// Builds the path of a rolled file from (active path, window start secs, sequence in window)
+pub type RollNaming = fn(&Path, u64, u32) -> PathBuf;
+
+/// Default naming: `app.log` rolls to `app.log.<window start>.<seq>`
+fn default_naming(path: &Path, window: u64, seq: u32) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".{window}.{seq}"));
+    PathBuf::from(name)
+}
+
+/// File names the naming scheme produces: fixed text around the window and sequence numbers
+struct RolledPattern {
+    prefix: String,
+    suffix: String,
+    /// Non-digit characters the scheme puts between the numbers
+    separators: String,
+}
+
+impl RolledPattern {
+    /// Derive the pattern from the names of the first and last possible rolled files
+    fn new(first: &str, last: &str) -> Option<Self> {
+        let prefix = &first[..common_prefix(first, last)];
+        let (first, last) = (&first[prefix.len()..], &last[prefix.len()..]);
+        let suffix = &first[first.len() - common_suffix(first, last)..];
+        if prefix.is_empty() && suffix.is_empty() {
+            return None;
+        }
+        let numbers = |name: &'_ str| name[..name.len() - suffix.len()].to_owned();
+        let separators = numbers(first)
+            .chars()
+            .chain(numbers(last).chars())
+            .filter(|c| !c.is_ascii_digit())
+            .collect();
+        Some(Self {
+            prefix: prefix.to_owned(),
+            suffix: suffix.to_owned(),
+            separators,
+        })
+    }
+
+    /// Whether `name` could have been produced by the naming scheme
+    fn matches(&self, name: &str) -> bool {
+        let Some(numbers) = name
+            .strip_prefix(self.prefix.as_str())
+            .and_then(|rest| rest.strip_suffix(self.suffix.as_str()))
+        else {
+            return false;
+        };
+        numbers.starts_with(|c: char| c.is_ascii_digit())
+            && numbers.ends_with(|c: char| c.is_ascii_digit())
+            && numbers
+                .chars()
+                .all(|c| c.is_ascii_digit() || self.separators.contains(c))
+    }
+}
+
+/// Byte length of the longest common prefix of `a` and `b`
+fn common_prefix(a: &str, b: &str) -> usize {
+    a.char_indices()
+        .zip(b.chars())
+        .find(|((_, x), y)| x != y)
+        .map_or(a.len().min(b.len()), |((index, _), _)| index)
+}
+
+/// Byte length of the longest common suffix of `a` and `b`
+fn common_suffix(a: &str, b: &str) -> usize {
+    a.char_indices()
+        .rev()
+        .zip(b.chars().rev())
+        .find(|((_, x), y)| x != y)
+        .map_or(a.len().min(b.len()), |((index, x), _)| {
+            a.len() - index - x.len_utf8()
+        })
+}
+
+/// Writer that outputs to a file and rotates it by size and/or time (ANSI codes stripped)
+///
+/// The file is rotated on whichever limit trips first. Rotation only happens
+/// when a write arrives, so idle periods never produce empty files.
+///
+/// Rolled files left by earlier runs are picked up on the first write: they
+/// count towards `max_files` and are never overwritten (the sequence skips
+/// names that are taken). They are recognized by the naming scheme: the text
+/// around its numbers must match and the numbers themselves must be digits,
+/// so `app.log.1700000000.0` counts but `app.log.bak` does not.
+pub struct RollingFileWriter {
+    path: PathBuf,
+    file: Option<File>,
+    size: u64,
+    window: u64,
+    seq: u32,
+    max_size: Option<u64>,
+    interval: Option<RollInterval>,
+    max_files: Option<usize>,
+    naming: RollNaming,
+    clock: Box<dyn Fn() -> SystemTime + Send>,
+    rolled: VecDeque<PathBuf>,
+    /// Whether rolled files from earlier runs have been looked up
+    discovered: bool,
+}
+
+impl RollingFileWriter {
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            file: None,
+            size: 0,
+            window: 0,
+            seq: 0,
+            max_size: None,
+            interval: None,
+            max_files: None,
+            naming: default_naming,
+            clock: Box::new(clock::now),
+            rolled: VecDeque::new(),
+            discovered: false,
+        }
+    }
+
+    /// Rotate once the file would exceed `bytes`
+    pub fn max_size(mut self, bytes: u64) -> Self {
+        self.max_size = Some(bytes);
+        self
+    }
+
+    /// Rotate when the time window changes
+    pub fn interval(mut self, interval: RollInterval) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    /// Keep at most `count` rolled files, deleting the oldest
+    pub fn max_files(mut self, count: usize) -> Self {
+        self.max_files = Some(count);
+        self
+    }
+
+    /// Use a custom naming scheme for rolled files
+    pub fn naming(mut self, naming: RollNaming) -> Self {
+        self.naming = naming;
+        self
+    }
+
+    /// Use a custom time source instead of the global clock
+    pub fn with_clock(mut self, clock: impl Fn() -> SystemTime + Send + 'static) -> Self {
+        self.clock = Box::new(clock);
+        self
+    }
+
+    /// Start of the time window containing the current time (0 without an interval)
+    fn current_window(&self) -> u64 {
+        let Some(interval) = self.interval else {
+            return 0;
+        };
+        let secs = (self.clock)()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        secs - secs % interval.secs()
+    }
+
+    /// Collect rolled files from earlier runs, oldest first
+    fn discover_rolled(&mut self) {
+        self.discovered = true;
+        let first = (self.naming)(&self.path, 0, 0);
+        let last = (self.naming)(&self.path, u64::MAX, u32::MAX);
+        let (Some(dir), Some(first), Some(last)) =
+            (first.parent(), first.file_name(), last.file_name())
+        else {
+            return;
+        };
+        let (first, last) = (first.to_string_lossy(), last.to_string_lossy());
+        let Some(pattern) = RolledPattern::new(&first, &last) else {
+            return;
+        };
+
+        let dir = if dir.as_os_str().is_empty() {
+            Path::new(".")
+        } else {
+            dir
+        };
+        let Ok(entries) = fs::read_dir(dir) else {
+            return;
+        };
+        let mut found: Vec<(SystemTime, PathBuf)> = entries
+            .flatten()
+            .filter(|entry| pattern.matches(&entry.file_name().to_string_lossy()))
+            .map(|entry| entry.path())
+            .filter(|path| path.file_name() != self.path.file_name() && path.is_file())
+            .map(|path| {
+                let modified = fs::metadata(&path)
+                    .and_then(|meta| meta.modified())
+                    .unwrap_or(SystemTime::UNIX_EPOCH);
+                (modified, path)
+            })
+            .collect();
+        found.sort();
+        self.rolled = found.into_iter().map(|(_, path)| path).collect();
+    }
+
+    /// Open (or reopen) the active file in append mode
+    fn open(&mut self, window: u64) -> io::Result<()> {
+        if !self.discovered {
+            self.discover_rolled();
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        self.size = file.metadata()?.len();
+        self.file = Some(file);
+        if window != self.window {
+            self.window = window;
+            self.seq = 0;
+        }
+        Ok(())
+    }
+
+    /// Close the active file, rename it and prune old files
+    fn roll(&mut self) -> io::Result<()> {
+        if let Some(mut file) = self.file.take() {
+            file.flush()?;
+        }
+
+        // Skip names taken by earlier runs rather than overwrite them
+        let mut target = (self.naming)(&self.path, self.window, self.seq);
+        while target.exists() {
+            self.seq += 1;
+            target = (self.naming)(&self.path, self.window, self.seq);
+        }
+        fs::rename(&self.path, &target)?;
+        self.seq += 1;
+        self.rolled.push_back(target);
+
+        if let Some(max) = self.max_files {
+            while self.rolled.len() > max {
+                if let Some(oldest) = self.rolled.pop_front() {
+                    let _ = fs::remove_file(oldest);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Write for RollingFileWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let clean = strip_ansi(buf);
+        let window = self.current_window();
+
+        if self.file.is_none() {
+            self.open(window)?;
+        }
+
+        let time_roll = self.interval.is_some() && window != self.window;
+        let size_roll = self
+            .max_size
+            .is_some_and(|max| self.size > 0 && self.size + clean.len() as u64 > max);
+
+        if size_roll || (time_roll && self.size > 0) {
+            self.roll()?;
+            self.open(window)?;
+        } else if time_roll {
+            self.window = window;
+            self.seq = 0;
+        }
+
+        if let Some(file) = self.file.as_mut() {
+            file.write_all(&clean)?;
+        }
+        self.size += clean.len() as u64;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self.file.as_mut() {
+            Some(file) => file.flush(),
+            None => Ok(()),
+        }
+    }
+}
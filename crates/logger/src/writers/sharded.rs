@@ -0,0 +1,84 @@
+use crate::level::{Level, LEVEL_COUNT};
+use crate::record::Record;
+use crate::writers::{RecordWriter, RollInterval, RollingFileWriter};
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+/// Writer that appends each record to a file named after its level
+///
+/// Records go to `debug.log`, `info.log`, `warn.log` or `error.log` in `dir`;
+/// files (and the directory) are created on first use. Rotation settings
+/// apply to each shard independently.
+pub struct LevelShardedFileWriter {
+    dir: PathBuf,
+    shards: [Option<RollingFileWriter>; LEVEL_COUNT],
+    max_size: Option<u64>,
+    interval: Option<RollInterval>,
+    max_files: Option<usize>,
+}
+
+impl LevelShardedFileWriter {
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Self {
+        Self {
+            dir: dir.into(),
+            shards: Default::default(),
+            max_size: None,
+            interval: None,
+            max_files: None,
+        }
+    }
+
+    /// Rotate each shard once it would exceed `bytes`
+    pub fn max_size(mut self, bytes: u64) -> Self {
+        self.max_size = Some(bytes);
+        self
+    }
+
+    /// Rotate each shard when the time window changes
+    pub fn interval(mut self, interval: RollInterval) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    /// Keep at most `count` rolled files per shard
+    pub fn max_files(mut self, count: usize) -> Self {
+        self.max_files = Some(count);
+        self
+    }
+
+    /// Get the shard for `level`, creating it on first use
+    fn shard(&mut self, level: Level) -> io::Result<&mut RollingFileWriter> {
+        let index = (level as usize).min(self.shards.len() - 1);
+        match &mut self.shards[index] {
+            Some(shard) => Ok(shard),
+            slot @ None => {
+                fs::create_dir_all(&self.dir)?;
+                let mut shard = RollingFileWriter::new(self.dir.join(format!("{level}.log")));
+                if let Some(bytes) = self.max_size {
+                    shard = shard.max_size(bytes);
+                }
+                if let Some(interval) = self.interval {
+                    shard = shard.interval(interval);
+                }
+                if let Some(count) = self.max_files {
+                    shard = shard.max_files(count);
+                }
+                Ok(slot.insert(shard))
+            }
+        }
+    }
+}
+
+impl RecordWriter for LevelShardedFileWriter {
+    fn write_record(&mut self, record: &Record, line: &[u8]) -> io::Result<()> {
+        self.shard(record.level())?.write_all(line)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        for shard in self.shards.iter_mut().flatten() {
+            Write::flush(shard)?;
+        }
+        Ok(())
+    }
+}
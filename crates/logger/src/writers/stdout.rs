@@ -1,21 +1,44 @@
 use std::io::{self, Write};
 
 /// Writer that outputs to stdout
-#[derive(Default)]
-pub struct StdoutWriter;
+/// All levels, including warnings and errors, go to stdout (never stderr)
+///
+/// The stdout handle is cached and locked once per line, so each line is
+/// written whole. The lock is not held between lines, so user code printing
+/// to stdout from other threads cannot deadlock with the logger.
+///
+/// On Windows, when stdout is an interactive console, the standard library
+/// transcodes each line to UTF-16 and writes it with `WriteConsoleW`, so
+/// non-ASCII text displays correctly whatever the console code page.
+/// Redirected output (files, pipes) stays UTF-8.
+pub struct StdoutWriter {
+    stdout: io::Stdout,
+}
 
 impl StdoutWriter {
     pub fn new() -> Self {
-        Self
+        Self {
+            stdout: io::stdout(),
+        }
+    }
+}
+
+impl Default for StdoutWriter {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
 impl Write for StdoutWriter {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        io::stdout().write(buf)
+        self.stdout.lock().write(buf)
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.stdout.lock().write_all(buf)
     }
 
     fn flush(&mut self) -> io::Result<()> {
-        io::stdout().flush()
+        self.stdout.flush()
     }
 }
@@ -0,0 +1,66 @@
+use crate::record::Record;
+use crate::writers::RecordWriter;
+use std::io;
+use std::time::Duration;
+
+/// Writer that sends every record to several writers
+///
+/// Each branch receives the record as well as the line, so record-aware
+/// writers such as `LeveledWriter` can route by level. A failing branch does
+/// not stop the others; the first error is returned.
+///
+/// ```ignore
+/// set_output(
+///     TeeWriter::new()
+///         .branch(LeveledWriter::new(StdoutWriter::new(), Level::Info))
+///         .branch(LeveledWriter::new(FileWriter::new("app.log")?, Level::Debug)),
+/// );
+/// ```
+#[derive(Default)]
+pub struct TeeWriter {
+    branches: Vec<Box<dyn RecordWriter>>,
+}
+
+impl TeeWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a writer receiving every record
+    pub fn branch<W: RecordWriter + 'static>(mut self, writer: W) -> Self {
+        self.branches.push(Box::new(writer));
+        self
+    }
+}
+
+impl RecordWriter for TeeWriter {
+    fn write_record(&mut self, record: &Record, line: &[u8]) -> io::Result<()> {
+        let mut result = Ok(());
+        for branch in &mut self.branches {
+            let written = branch.write_record(record, line);
+            if result.is_ok() {
+                result = written;
+            }
+        }
+        result
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let mut result = Ok(());
+        for branch in &mut self.branches {
+            let flushed = branch.flush();
+            if result.is_ok() {
+                result = flushed;
+            }
+        }
+        result
+    }
+
+    fn shutdown(&mut self, timeout: Duration) -> bool {
+        let mut drained = true;
+        for branch in &mut self.branches {
+            drained &= branch.shutdown(timeout);
+        }
+        drained
+    }
+}
@@ -0,0 +1,27 @@
+use std::io::{self, Write};
+
+/// Writer that prints through `print!`, so libtest captures it per test
+///
+/// `StdoutWriter` writes to the process's stdout handle directly, which the
+/// test harness does not capture; with this writer, logs of passing tests
+/// stay hidden and failing tests show their logs with the rest of their
+/// output.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TestWriter;
+
+impl TestWriter {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Write for TestWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        print!("{}", String::from_utf8_lossy(buf));
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        io::stdout().flush()
+    }
+}
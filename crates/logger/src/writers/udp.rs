@@ -0,0 +1,88 @@
+use crate::config::line_around_message;
+use crate::record::Record;
+use crate::utils::strip_ansi;
+use crate::writers::oversized::{fit_message, MIN_MESSAGE_BUDGET};
+use crate::writers::RecordWriter;
+use std::borrow::Cow;
+use std::io;
+use std::net::{ToSocketAddrs, UdpSocket};
+
+/// Largest UDP payload over IPv4
+const MAX_UDP_PAYLOAD: usize = 65_507;
+
+/// Writer that sends each line as one UDP datagram (ANSI codes stripped)
+///
+/// Lines over `max_datagram` bytes are handled by `set_oversized_policy`;
+/// split pieces repeat the line's header and fields so each is parseable alone.
+/// When the header and fields alone leave too little room, the whole line is
+/// truncated or split instead.
+pub struct UdpWriter {
+    socket: UdpSocket,
+    max_datagram: usize,
+}
+
+impl UdpWriter {
+    /// Send to `addr` from an ephemeral local port
+    pub fn new<A: ToSocketAddrs>(addr: A) -> io::Result<Self> {
+        let addr = addr
+            .to_socket_addrs()?
+            .next()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "no address"))?;
+        let local = if addr.is_ipv4() {
+            "0.0.0.0:0"
+        } else {
+            "[::]:0"
+        };
+        let socket = UdpSocket::bind(local)?;
+        socket.connect(addr)?;
+
+        Ok(Self {
+            socket,
+            max_datagram: MAX_UDP_PAYLOAD,
+        })
+    }
+
+    /// Set the largest datagram to send (default 65507 bytes)
+    pub fn max_datagram(mut self, size: usize) -> Self {
+        self.max_datagram = size;
+        self
+    }
+}
+
+impl RecordWriter for UdpWriter {
+    fn write_record(&mut self, record: &Record, line: &[u8]) -> io::Result<()> {
+        let clean = strip_ansi(line);
+        if clean.len() <= self.max_datagram {
+            self.socket.send(&clean)?;
+            return Ok(());
+        }
+
+        // Repeat everything around the message in each datagram, unless the
+        // rest of the line leaves no room for it
+        let line = String::from_utf8_lossy(&clean);
+        let datagrams: Vec<String> = match line_around_message(record) {
+            Some((head, tail))
+                if head.len() + tail.len() + MIN_MESSAGE_BUDGET <= self.max_datagram =>
+            {
+                let budget = self.max_datagram - head.len() - tail.len();
+                fit_message(record.message(), budget)
+                    .into_iter()
+                    .map(|piece| format!("{head}{piece}{tail}"))
+                    .collect()
+            }
+            _ => fit_message(&line, self.max_datagram)
+                .into_iter()
+                .map(Cow::into_owned)
+                .collect(),
+        };
+
+        for datagram in datagrams {
+            self.socket.send(datagram.as_bytes())?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+mod common;
+
+use common::{capture, compiled_in};
+use rust_telemetry::{clear_clock, debug, error, set_adaptive_level, set_clock, Level, MockClock};
+use std::time::{Duration, UNIX_EPOCH};
+
+#[test]
+fn test_high_rate_drops_debug_but_not_errors() {
+    if !compiled_in(Level::Debug) {
+        return;
+    }
+    let clock = MockClock::new(UNIX_EPOCH);
+    let output = capture(|| {
+        set_clock(Box::new(clock.clone()));
+        set_adaptive_level(true, 5);
+        for i in 0..20 {
+            debug!("debug {i}");
+        }
+        error!("still here");
+        set_adaptive_level(false, 0);
+        clear_clock();
+    });
+
+    let debug_lines = output
+        .lines()
+        .filter(|line| line.contains(" debg "))
+        .count();
+    assert_eq!(debug_lines, 5, "{output}");
+    assert!(output.contains("errr still here"), "{output}");
+}
+
+#[test]
+fn test_level_relaxes_when_rate_subsides() {
+    if !compiled_in(Level::Debug) {
+        return;
+    }
+    let clock = MockClock::new(UNIX_EPOCH);
+    let output = capture(|| {
+        set_clock(Box::new(clock.clone()));
+        set_adaptive_level(true, 2);
+        for _ in 0..10 {
+            debug!("burst");
+        }
+        clock.advance(Duration::from_secs(5));
+        debug!("calm");
+        set_adaptive_level(false, 0);
+        clear_clock();
+    });
+
+    assert_eq!(output.matches("burst").count(), 2, "{output}");
+    assert!(output.contains("debg calm"), "{output}");
+}
+
+#[test]
+fn test_disabled_keeps_everything() {
+    if !compiled_in(Level::Debug) {
+        return;
+    }
+    let output = capture(|| {
+        set_adaptive_level(false, 1);
+        for _ in 0..5 {
+            debug!("line");
+        }
+    });
+
+    assert_eq!(output.lines().count(), 5);
+}
@@ -0,0 +1,48 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{
+    audit, clear_audit_output, info, set_adaptive_level, set_audit_output, set_max_level, Level,
+    MemoryWriter,
+};
+
+#[test]
+fn test_audit_bypasses_level_and_sampling() {
+    let trail = MemoryWriter::new();
+    let output = capture(|| {
+        set_audit_output(trail.writer());
+        set_max_level(Level::Off);
+        set_adaptive_level(true, 0);
+        info!("dropped");
+        audit!("user {} deleted", 42);
+        set_adaptive_level(false, 0);
+        set_max_level(Level::Debug);
+        clear_audit_output();
+    });
+
+    assert_eq!(output, "");
+    assert_eq!(trail.contents(), "00:00:00.000 info user 42 deleted\n");
+}
+
+#[test]
+fn test_audit_without_output_uses_normal_output() {
+    let output = capture(|| {
+        set_max_level(Level::Error);
+        audit!("config changed");
+        set_max_level(Level::Debug);
+    });
+
+    assert_eq!(output, "00:00:00.000 info config changed\n");
+}
+
+#[test]
+fn test_audit_escapes_embedded_newlines() {
+    let trail = MemoryWriter::new();
+    capture(|| {
+        set_audit_output(trail.writer());
+        audit!("a\nb");
+        clear_audit_output();
+    });
+
+    assert_eq!(trail.contents(), "00:00:00.000 info a\\nb\n");
+}
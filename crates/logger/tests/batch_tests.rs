@@ -0,0 +1,103 @@
+mod common;
+
+use common::lock;
+use rust_telemetry::{
+    clear_output, clear_timestamp, info, set_color_enabled, set_output, set_timestamp, shutdown,
+    BatchWriter,
+};
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Writer counting how many times it is written to
+#[derive(Clone, Default)]
+struct CountingWriter(Arc<Mutex<(usize, Vec<u8>)>>);
+
+impl CountingWriter {
+    fn writes(&self) -> usize {
+        self.0.lock().unwrap().0
+    }
+
+    fn contents(&self) -> String {
+        String::from_utf8_lossy(&self.0.lock().unwrap().1).to_string()
+    }
+}
+
+impl Write for CountingWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut state = self.0.lock().unwrap();
+        state.0 += 1;
+        state.1.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_batch_is_a_single_write() {
+    let _guard = lock();
+    let counter = CountingWriter::default();
+    set_output(BatchWriter::new(counter.clone(), 3));
+    set_timestamp("00:00:00.000");
+    set_color_enabled(false);
+
+    info("one");
+    info("two");
+    assert_eq!(counter.writes(), 0);
+    info("three");
+
+    set_color_enabled(true);
+    clear_timestamp();
+    clear_output();
+    assert_eq!(counter.writes(), 1);
+    assert_eq!(
+        counter.contents(),
+        "00:00:00.000 info one\n00:00:00.000 info two\n00:00:00.000 info three\n"
+    );
+}
+
+#[test]
+fn test_batch_header_footer_and_drop() {
+    let _guard = lock();
+    let counter = CountingWriter::default();
+    set_output(
+        BatchWriter::new(counter.clone(), 10)
+            .header(b"[")
+            .separator(b",")
+            .footer(b"]"),
+    );
+    set_timestamp("t");
+    set_color_enabled(false);
+
+    info("a");
+    info("b");
+    assert_eq!(counter.writes(), 0);
+
+    set_color_enabled(true);
+    clear_timestamp();
+    clear_output();
+    assert_eq!(counter.writes(), 1);
+    assert_eq!(counter.contents(), "[t info a\n,t info b\n]");
+}
+
+#[test]
+fn test_shutdown_writes_partial_batch() {
+    let _guard = lock();
+    let counter = CountingWriter::default();
+    set_output(BatchWriter::new(counter.clone(), 10));
+    set_timestamp("t");
+    set_color_enabled(false);
+
+    info("a");
+    assert_eq!(counter.writes(), 0);
+    assert!(shutdown(Duration::from_secs(1)));
+    assert_eq!(counter.writes(), 1);
+    assert_eq!(counter.contents(), "t info a\n");
+
+    set_color_enabled(true);
+    clear_timestamp();
+    clear_output();
+}
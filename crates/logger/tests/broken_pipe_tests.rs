@@ -0,0 +1,57 @@
+mod common;
+
+use common::lock;
+use rust_telemetry::{clear_output, info, set_output, set_stderr_fallback, stats, MemoryWriter};
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// Writer whose reader has gone away, counting attempted writes
+#[derive(Clone, Default)]
+struct ClosedPipe(Arc<AtomicUsize>);
+
+impl Write for ClosedPipe {
+    fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+        self.0.fetch_add(1, Ordering::Relaxed);
+        Err(io::ErrorKind::BrokenPipe.into())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_broken_pipe_goes_quiet() {
+    let _guard = lock();
+    let pipe = ClosedPipe::default();
+    stats().reset();
+    set_output(pipe.clone());
+    set_stderr_fallback(true);
+
+    for i in 0..5 {
+        info(&format!("line {i}"));
+    }
+
+    set_stderr_fallback(false);
+    assert_eq!(pipe.0.load(Ordering::Relaxed), 1);
+    assert_eq!(stats().write_failures(), 1);
+    assert_eq!(stats().fallback_writes(), 1);
+
+    clear_output();
+    stats().reset();
+}
+
+#[test]
+fn test_new_output_resumes_writing() {
+    let _guard = lock();
+    set_output(ClosedPipe::default());
+    info("dropped");
+
+    let buffer = MemoryWriter::new();
+    set_output(buffer.writer());
+    info("delivered");
+    clear_output();
+
+    assert!(buffer.contents().ends_with("info delivered\n"));
+}
@@ -0,0 +1,25 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{log_bytes, Level};
+
+#[test]
+fn test_log_bytes_escapes_nul_and_invalid_utf8() {
+    let output = capture(|| {
+        log_bytes(Level::Info, b"frame\x00\x01 ok \xff\xfe caf\xc3\xa9 \\");
+    });
+
+    assert_eq!(
+        output,
+        "00:00:00.000 info frame\\x00\\x01 ok \\xff\\xfe caf\u{e9} \\\\\n"
+    );
+}
+
+#[test]
+fn test_log_bytes_escapes_newlines() {
+    let output = capture(|| {
+        log_bytes(Level::Warn, b"a\nb\tc");
+    });
+
+    assert_eq!(output, "00:00:00.000 warn a\\x0ab\\x09c\n");
+}
@@ -0,0 +1,39 @@
+mod common;
+
+use common::lock;
+use rust_telemetry::{
+    bytes_written, clear_output, clear_timestamp, info, set_color_enabled, set_output,
+    set_timestamp, MemoryWriter,
+};
+
+#[test]
+fn test_counts_line_bytes() {
+    let _guard = lock();
+    let buffer = MemoryWriter::new();
+    set_output(buffer.writer());
+    set_timestamp("00:00:00.000");
+    set_color_enabled(false);
+
+    info("hello");
+    info("world!");
+
+    let written = bytes_written();
+    set_color_enabled(true);
+    clear_timestamp();
+    clear_output();
+    // "00:00:00.000 info " is 18 bytes, plus the message and "\n"
+    assert_eq!(written, (18 + 5 + 1) + (18 + 6 + 1));
+}
+
+#[test]
+fn test_resets_on_writer_swap() {
+    let _guard = lock();
+    set_output(MemoryWriter::new().writer());
+    info("before swap");
+    assert!(bytes_written() > 0);
+
+    set_output(MemoryWriter::new().writer());
+    assert_eq!(bytes_written(), 0);
+    clear_output();
+    assert_eq!(bytes_written(), 0);
+}
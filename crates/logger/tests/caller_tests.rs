@@ -0,0 +1,32 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{clear_on_record, log_at_caller, set_on_record, Level};
+use std::sync::{Arc, Mutex};
+
+/// A project-style helper wrapping the logger
+#[track_caller]
+fn log_request(path: &str) {
+    log_at_caller(Level::Info, format_args!("GET {path}"));
+}
+
+#[test]
+fn test_track_caller_wrapper_reports_call_site() {
+    let location = Arc::new(Mutex::new(None));
+    let seen = Arc::clone(&location);
+
+    let expected_line = line!() + 5;
+    let output = capture(|| {
+        set_on_record(Box::new(move |record| {
+            *seen.lock().unwrap() = record.file().map(str::to_string).zip(record.line());
+        }));
+        log_request("/health");
+        clear_on_record();
+    });
+
+    assert_eq!(output, "00:00:00.000 info GET /health\n");
+    assert_eq!(
+        *location.lock().unwrap(),
+        Some((file!().to_string(), expected_line))
+    );
+}
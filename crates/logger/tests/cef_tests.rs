@@ -0,0 +1,50 @@
+#![cfg(feature = "cef")]
+
+mod common;
+
+use common::capture;
+use rust_telemetry::{
+    log, set_cef_device, set_format, CefFormatter, Field, Format, Formatter, Level, Record,
+};
+use std::time::Duration;
+
+#[test]
+fn test_header_and_extension_escaping() {
+    let fields: [Field; 2] = [("query", &"a=b\\c"), ("src-ip", &"10.0.0.1")];
+    let record = Record::new(Level::Warn, "login|failed")
+        .with_time(Duration::from_millis(1_700_000_000_123))
+        .with_fields(&fields);
+    let output = capture(|| {
+        set_cef_device("Acme|Corp", "Gate", "1.0");
+        set_format(Format::Cef);
+        log(&record);
+        set_format(Format::Text);
+    });
+
+    let key = format!("{:016x}", record.dedup_key());
+    let expected_start =
+        format!("00:00:00.000 CEF:0|Acme\\|Corp|Gate|1.0|{key}|login\\|failed|6|rt=");
+    assert!(output.starts_with(&expected_start), "{output}");
+    assert!(
+        output.ends_with(" query=a\\=b\\\\c src_ip=10.0.0.1\n"),
+        "{output}"
+    );
+}
+
+#[test]
+fn test_severity_mapping() {
+    assert_eq!(CefFormatter::severity(Level::Debug), 1);
+    assert_eq!(CefFormatter::severity(Level::Info), 3);
+    assert_eq!(CefFormatter::severity(Level::Warn), 6);
+    assert_eq!(CefFormatter::severity(Level::Error), 9);
+}
+
+#[test]
+fn test_record_time_in_rt() {
+    let record = Record::new(Level::Info, "up").with_time(Duration::from_millis(1_234));
+    let mut line = String::new();
+    CefFormatter.format(&record, "", &mut line);
+
+    assert!(line.starts_with("CEF:0|"), "{line}");
+    assert!(line.ends_with("|up|3|rt=1234"), "{line}");
+}
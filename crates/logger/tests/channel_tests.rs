@@ -0,0 +1,51 @@
+mod common;
+
+use common::lock;
+use rust_telemetry::{
+    clear_output, clear_timestamp, info, set_output, set_timestamp, warn, ChannelWriter,
+    OverflowPolicy,
+};
+use std::sync::mpsc;
+
+#[test]
+fn test_lines_arrive_in_order() {
+    let _guard = lock();
+    let (sender, receiver) = mpsc::sync_channel(16);
+    set_output(ChannelWriter::new(sender));
+    set_timestamp("00:00:00.000");
+
+    info("first");
+    warn("second");
+    info("third");
+
+    clear_timestamp();
+    clear_output();
+
+    let lines: Vec<String> = receiver.iter().collect();
+    assert_eq!(
+        lines,
+        [
+            "00:00:00.000 info first\n",
+            "00:00:00.000 warn second\n",
+            "00:00:00.000 info third\n",
+        ]
+    );
+}
+
+#[test]
+fn test_full_channel_drops() {
+    let _guard = lock();
+    let (sender, receiver) = mpsc::sync_channel(1);
+    let writer = ChannelWriter::new(sender).overflow_policy(OverflowPolicy::Drop);
+    set_output(writer.clone());
+
+    info("kept");
+    info("dropped");
+    info("dropped too");
+
+    clear_output();
+    assert_eq!(writer.dropped_count(), 2);
+    drop(writer);
+
+    assert_eq!(receiver.iter().count(), 1);
+}
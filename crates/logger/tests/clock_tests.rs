@@ -0,0 +1,59 @@
+mod common;
+
+use common::lock;
+use rust_telemetry::{
+    clear_clock, clear_output, info, set_clock, set_output, MemoryWriter, MockClock, RollInterval,
+    RollingFileWriter,
+};
+use std::fs;
+use std::io::Write;
+use std::time::{Duration, SystemTime};
+
+#[test]
+fn test_timestamp_from_mock_clock() {
+    let _guard = lock();
+    let buffer = MemoryWriter::new();
+    let clock = MockClock::new(SystemTime::UNIX_EPOCH + Duration::from_millis(3_661_500));
+    set_output(buffer.writer());
+    set_clock(Box::new(clock.clone()));
+
+    info("first");
+    clock.advance(Duration::from_millis(1_250));
+    info("second");
+
+    clear_clock();
+    clear_output();
+
+    assert_eq!(
+        buffer.contents(),
+        "01:01:01.500 info first\n01:01:02.750 info second\n"
+    );
+}
+
+#[test]
+fn test_rolling_writer_uses_global_clock() {
+    let _guard = lock();
+    let dir = std::env::temp_dir().join(format!("rust-telemetry-clock-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+
+    let clock = MockClock::new(SystemTime::UNIX_EPOCH + Duration::from_secs(3600));
+    set_clock(Box::new(clock.clone()));
+
+    let mut writer = RollingFileWriter::new(dir.join("app.log")).interval(RollInterval::Hourly);
+    writer.write_all(b"first hour\n").unwrap();
+    clock.advance(Duration::from_secs(3600));
+    writer.write_all(b"second hour\n").unwrap();
+    writer.flush().unwrap();
+
+    clear_clock();
+
+    assert_eq!(
+        fs::read_to_string(dir.join("app.log.3600.0")).unwrap(),
+        "first hour\n"
+    );
+    assert_eq!(
+        fs::read_to_string(dir.join("app.log")).unwrap(),
+        "second hour\n"
+    );
+}
@@ -0,0 +1,50 @@
+mod common;
+
+use common::lock;
+use rust_telemetry::{
+    clear_coarse_time, clear_output, info, set_coarse_time, set_format, set_formatter, set_output,
+    Format, Formatter, MemoryWriter, Record,
+};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// Writes each record's time in milliseconds since the epoch
+struct TimeFormatter;
+
+impl Formatter for TimeFormatter {
+    fn format(&self, record: &Record, _time: &str, out: &mut String) {
+        out.push_str(&record.time().unwrap_or_default().as_millis().to_string());
+    }
+}
+
+#[test]
+fn test_coarse_time_advances_within_resolution() {
+    let _guard = lock();
+    let buffer = MemoryWriter::new();
+    set_output(buffer.writer());
+    set_formatter(Box::new(TimeFormatter));
+    let resolution = Duration::from_millis(5);
+
+    set_coarse_time(resolution);
+    let before = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_millis();
+    info("first");
+    thread::sleep(Duration::from_millis(50));
+    info("second");
+    clear_coarse_time();
+
+    set_format(Format::Text);
+    clear_output();
+    let times: Vec<u128> = buffer
+        .contents()
+        .lines()
+        .map(|line| line.parse().unwrap())
+        .collect();
+
+    // The cache may lag by one refresh (plus scheduling slack)
+    let slack = resolution.as_millis() + 20;
+    assert!(times[0] + slack >= before, "{times:?} vs {before}");
+    assert!(times[1] >= times[0] + 50 - slack, "{times:?}");
+}
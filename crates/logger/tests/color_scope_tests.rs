@@ -0,0 +1,51 @@
+mod common;
+
+use common::capture_raw;
+use rust_telemetry::{
+    error, log, set_color_scope, set_format, ColorScope, Field, Format, Level, Record,
+};
+
+fn render(scope: ColorScope) -> String {
+    let fields: [Field; 1] = [("code", &7)];
+    capture_raw(|| {
+        set_color_scope(scope);
+        log(&Record::new(Level::Error, "boom").with_fields(&fields));
+        set_color_scope(ColorScope::LevelOnly);
+    })
+}
+
+#[test]
+fn test_level_only() {
+    assert_eq!(
+        render(ColorScope::LevelOnly),
+        "00:00:00.000 \x1b[31merrr\x1b[0m boom code=7\n"
+    );
+}
+
+#[test]
+fn test_level_and_message() {
+    assert_eq!(
+        render(ColorScope::LevelAndMessage),
+        "00:00:00.000 \x1b[31merrr\x1b[0m \x1b[31mboom\x1b[0m code=7\n"
+    );
+}
+
+#[test]
+fn test_whole_line() {
+    assert_eq!(
+        render(ColorScope::WholeLine),
+        "\x1b[31m00:00:00.000 errr boom code=7\x1b[0m\n"
+    );
+}
+
+#[test]
+fn test_whole_line_pretty_colors_first_line() {
+    let output = capture_raw(|| {
+        set_format(Format::Pretty);
+        set_color_scope(ColorScope::WholeLine);
+        error("boom");
+        set_color_scope(ColorScope::LevelOnly);
+        set_format(Format::Text);
+    });
+    assert_eq!(output, "\x1b[31m00:00:00.000 errr boom\x1b[0m\n");
+}
@@ -0,0 +1,76 @@
+#![allow(dead_code)]
+
+use rust_telemetry::{
+    clear_output, clear_timestamp, set_output, set_timestamp, Level, MemoryWriter, STATIC_MAX_LEVEL,
+};
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Serializes tests that touch the global logger state
+static LOCK: Mutex<()> = Mutex::new(());
+
+/// Take the global test lock (recovering if a previous test panicked)
+pub fn lock() -> MutexGuard<'static, ()> {
+    LOCK.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Whether macro calls at `level` are compiled in under the `max_level_*` features
+pub fn compiled_in(level: Level) -> bool {
+    level >= STATIC_MAX_LEVEL
+}
+
+/// Run `body` with a memory writer and fixed timestamp, returning the output
+pub fn capture(body: impl FnOnce()) -> String {
+    let _guard = lock();
+    let buffer = MemoryWriter::new();
+    set_output(buffer.writer());
+    set_timestamp("00:00:00.000");
+    let reset = ResetCapture;
+    body();
+    drop(reset);
+    buffer.contents()
+}
+
+/// Clears the capture's output and timestamp when it ends, even if its body panics
+struct ResetCapture;
+
+impl Drop for ResetCapture {
+    fn drop(&mut self) {
+        clear_timestamp();
+        clear_output();
+    }
+}
+
+/// Writer keeping the raw bytes, including ANSI codes (MemoryWriter strips them)
+#[derive(Clone, Default)]
+pub struct RawWriter(Arc<Mutex<Vec<u8>>>);
+
+impl RawWriter {
+    /// Get the contents as a string
+    pub fn contents(&self) -> String {
+        String::from_utf8_lossy(&self.0.lock().unwrap()).to_string()
+    }
+}
+
+impl Write for RawWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Like `capture`, but keeps ANSI codes in the output
+pub fn capture_raw(body: impl FnOnce()) -> String {
+    let _guard = lock();
+    let buffer = RawWriter::default();
+    set_output(buffer.clone());
+    set_timestamp("00:00:00.000");
+    let reset = ResetCapture;
+    body();
+    drop(reset);
+    buffer.contents()
+}
@@ -0,0 +1,32 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{info, set_compact_timestamps, set_timestamp, warn};
+
+#[test]
+fn test_repeated_timestamp_shown_once() {
+    let output = capture(|| {
+        set_compact_timestamps(true);
+        info("one");
+        info("two");
+        warn("three");
+        set_timestamp("00:00:00.001");
+        info("four");
+        set_compact_timestamps(false);
+    });
+
+    assert_eq!(
+        output,
+        "00:00:00.000 info one\n             info two\n             warn three\n00:00:00.001 info four\n"
+    );
+}
+
+#[test]
+fn test_disabled_by_default() {
+    let output = capture(|| {
+        info("one");
+        info("two");
+    });
+
+    assert_eq!(output, "00:00:00.000 info one\n00:00:00.000 info two\n");
+}
@@ -0,0 +1,56 @@
+mod common;
+
+use common::{capture, compiled_in};
+use rust_telemetry::{clear_on_record, info, set_on_record, warn, Level, Record};
+use std::sync::{Arc, Mutex};
+
+/// Log through the macros and collect each record's (template, dedup key)
+fn keys(body: impl FnOnce()) -> Vec<(Option<&'static str>, u64)> {
+    let keys = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&keys);
+    capture(|| {
+        set_on_record(Box::new(move |record: &Record| {
+            sink.lock()
+                .unwrap()
+                .push((record.template(), record.dedup_key()));
+        }));
+        body();
+        clear_on_record();
+    });
+    let keys = keys.lock().unwrap().clone();
+    keys
+}
+
+#[test]
+fn test_same_template_shares_key() {
+    if !compiled_in(Level::Info) {
+        return;
+    }
+    let keys = keys(|| {
+        for user in [1, 2] {
+            info!("user {} deleted", user);
+        }
+        info!("user {} created", 1);
+        warn!("user {} deleted", 1);
+    });
+
+    assert_eq!(keys[0].0, Some("user {} deleted"));
+    assert_eq!(keys[0].1, keys[1].1);
+    assert_ne!(keys[0].1, keys[2].1);
+    assert_ne!(keys[0].1, keys[3].1);
+}
+
+#[test]
+fn test_key_without_template_uses_message() {
+    let plain = Record::new(Level::Info, "started");
+    let fields: [rust_telemetry::Field; 1] = [("pid", &7)];
+
+    assert_eq!(plain.template(), None);
+    assert_eq!(plain.dedup_key(), plain.with_fields(&fields).dedup_key());
+    assert_eq!(
+        plain.dedup_key(),
+        Record::new(Level::Info, "other")
+            .with_template("started")
+            .dedup_key()
+    );
+}
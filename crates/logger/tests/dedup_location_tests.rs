@@ -0,0 +1,60 @@
+mod common;
+
+use common::{capture, compiled_in};
+use rust_telemetry::{set_dedup_location, set_format, Format, Level};
+
+#[test]
+fn test_same_site_shows_location_once() {
+    if !compiled_in(Level::Info) {
+        return;
+    }
+    let output = capture(|| {
+        set_format(Format::Pretty);
+        set_dedup_location(true);
+        for i in 0..3 {
+            rust_telemetry::info!("item {}", i);
+        }
+        set_dedup_location(false);
+        set_format(Format::Text);
+    });
+
+    assert_eq!(output.matches("\n    at ").count(), 1, "{output}");
+    assert!(output.starts_with(
+        "00:00:00.000 info item 0\n    at crates/logger/tests/dedup_location_tests.rs:"
+    ));
+    assert!(output.ends_with("00:00:00.000 info item 1\n00:00:00.000 info item 2\n"));
+}
+
+#[test]
+fn test_new_site_shows_location_again() {
+    if !compiled_in(Level::Info) {
+        return;
+    }
+    let output = capture(|| {
+        set_format(Format::Pretty);
+        set_dedup_location(true);
+        rust_telemetry::info!("first");
+        rust_telemetry::info!("second");
+        rust_telemetry::info!("second");
+        set_dedup_location(false);
+        set_format(Format::Text);
+    });
+
+    assert_eq!(output.matches("\n    at ").count(), 3, "{output}");
+}
+
+#[test]
+fn test_disabled_shows_every_location() {
+    if !compiled_in(Level::Info) {
+        return;
+    }
+    let output = capture(|| {
+        set_format(Format::Pretty);
+        for _ in 0..3 {
+            rust_telemetry::info!("loop");
+        }
+        set_format(Format::Text);
+    });
+
+    assert_eq!(output.matches("\n    at ").count(), 3, "{output}");
+}
@@ -0,0 +1,59 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{
+    clear_deferred_dump, debug, dump, error, info, set_deferred_dump, Level, DEFERRED_CAPACITY,
+};
+
+#[test]
+fn test_info_hidden_until_error() {
+    let output = capture(|| {
+        set_deferred_dump(Level::Error);
+        info("loading config");
+        debug("3 entries");
+        error("config invalid");
+        clear_deferred_dump();
+    });
+    assert_eq!(
+        output,
+        "00:00:00.000 info loading config\n\
+         00:00:00.000 debg 3 entries\n\
+         00:00:00.000 errr config invalid\n"
+    );
+}
+
+#[test]
+fn test_discarded_without_trigger() {
+    let output = capture(|| {
+        set_deferred_dump(Level::Error);
+        info("all good");
+        clear_deferred_dump();
+    });
+    assert_eq!(output, "");
+}
+
+#[test]
+fn test_explicit_dump() {
+    let output = capture(|| {
+        set_deferred_dump(Level::Error);
+        info("step one");
+        dump();
+        info("step two");
+        clear_deferred_dump();
+    });
+    assert_eq!(output, "00:00:00.000 info step one\n");
+}
+
+#[test]
+fn test_buffer_is_bounded() {
+    let output = capture(|| {
+        set_deferred_dump(Level::Error);
+        for i in 0..DEFERRED_CAPACITY + 10 {
+            info(&format!("line {i}"));
+        }
+        dump();
+        clear_deferred_dump();
+    });
+    assert_eq!(output.lines().count(), DEFERRED_CAPACITY);
+    assert_eq!(output.lines().next(), Some("00:00:00.000 info line 10"));
+}
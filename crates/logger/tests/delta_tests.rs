@@ -0,0 +1,37 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{info, set_show_delta};
+use std::thread;
+use std::time::Duration;
+
+/// Parse the `(+N.Nms)` delta from a line
+fn delta_ms(line: &str) -> f64 {
+    let start = line.find("(+").unwrap() + 2;
+    let end = line[start..].find("ms)").unwrap() + start;
+    line[start..end].parse().unwrap()
+}
+
+#[test]
+fn test_delta_since_last_log() {
+    let output = capture(|| {
+        set_show_delta(true);
+        // Run on a fresh thread so its first line has no previous log
+        thread::spawn(|| {
+            info("start");
+            thread::sleep(Duration::from_millis(20));
+            info("after sleep");
+        })
+        .join()
+        .unwrap();
+        set_show_delta(false);
+        info("plain");
+    });
+
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines[0], "00:00:00.000 info (+0ms) start");
+    assert!(lines[1].ends_with("ms) after sleep"));
+    let delta = delta_ms(lines[1]);
+    assert!((20.0..1000.0).contains(&delta), "delta {delta}");
+    assert_eq!(lines[2], "00:00:00.000 info plain");
+}
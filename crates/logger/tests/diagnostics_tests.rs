@@ -0,0 +1,108 @@
+mod common;
+
+use common::lock;
+use rust_telemetry::{
+    clear_module_levels, clear_output, clear_timestamp, effective_config, set_color_enabled,
+    set_color_scope, set_escape_nul, set_format, set_line_terminator, set_max_level,
+    set_module_level, set_output, set_sampling_rules, set_sanitize_messages, set_theme,
+    set_timestamp, set_timestamp_format, set_timestamp_position, set_timestamp_subsecond,
+    ColorScope, Format, Level, MemoryWriter, Theme, TimestampFormat, TimestampPosition,
+};
+use std::io::BufWriter;
+
+#[test]
+fn test_effective_config_reflects_settings() {
+    let _guard = lock();
+    set_max_level(Level::Warn);
+    set_module_level("db", Level::Debug);
+    set_format(Format::Pretty);
+    set_timestamp("12:00:00.000");
+    set_line_terminator("\r\n");
+    set_output(BufWriter::new(MemoryWriter::new()));
+
+    let config = effective_config();
+
+    set_output(MemoryWriter::new());
+    let writer = effective_config().writer;
+
+    clear_output();
+    let default_writer = effective_config().writer;
+
+    set_line_terminator("\n");
+    clear_timestamp();
+    set_format(Format::Text);
+    clear_module_levels();
+    set_max_level(Level::Debug);
+
+    assert_eq!(config.max_level, Level::Warn);
+    assert_eq!(config.module_levels, [("db".to_string(), Level::Debug)]);
+    assert_eq!(config.format, "pretty");
+    assert_eq!(config.fixed_timestamp.as_deref(), Some("12:00:00.000"));
+    assert_eq!(config.writer, "BufWriter<MemoryWriter>");
+    assert_eq!(
+        config.to_string(),
+        "level=warn modules=db=debug format=pretty writer=BufWriter<MemoryWriter> \
+         color=on scope=level theme=default timestamp=fixed(12:00:00.000) \
+         timestamp_position=Prefix subsecond=on sanitize=off escape_newlines=on escape_nul=on \
+         terminator=\"\\r\\n\""
+    );
+    assert_eq!(writer, "MemoryWriter");
+    assert_eq!(default_writer, "StdoutWriter");
+}
+
+#[test]
+fn test_effective_config_reports_output_settings() {
+    let _guard = lock();
+    let defaults = effective_config();
+
+    set_theme(Theme {
+        field_key: "\x1b[36m",
+        ..Theme::DEFAULT
+    });
+    set_color_enabled(false);
+    set_color_scope(ColorScope::WholeLine);
+    set_timestamp_format(TimestampFormat::Rfc3339);
+    set_timestamp_position(TimestampPosition::Suffix);
+    set_timestamp_subsecond(false);
+    set_sanitize_messages(true);
+    set_escape_nul(false);
+    set_sampling_rules(vec![("render::*".to_string(), Level::Debug, 0.25)]);
+
+    let config = effective_config();
+
+    set_sampling_rules(Vec::new());
+    set_escape_nul(true);
+    set_sanitize_messages(false);
+    set_timestamp_subsecond(true);
+    set_timestamp_position(TimestampPosition::Prefix);
+    set_timestamp_format(TimestampFormat::TimeOfDay);
+    set_theme(Theme::DEFAULT);
+
+    assert_eq!(defaults.theme, Theme::DEFAULT);
+    assert_eq!(defaults.timestamp_format, TimestampFormat::TimeOfDay);
+    assert_eq!(defaults.timestamp_position, TimestampPosition::Prefix);
+    assert!(defaults.timestamp_subsecond);
+    assert!(!defaults.sanitize_messages && defaults.escape_newlines && defaults.escape_nul);
+    assert!(defaults.sampling_rules.is_empty());
+    assert!(defaults.to_string().contains(
+        " color=on scope=level theme=default timestamp=TimeOfDay timestamp_position=Prefix \
+         subsecond=on sanitize=off escape_newlines=on escape_nul=on terminator="
+    ));
+
+    assert!(!config.theme.enabled);
+    assert_eq!(config.theme.scope, ColorScope::WholeLine);
+    assert_eq!(config.theme.field_key, "\x1b[36m");
+    assert_eq!(config.timestamp_format, TimestampFormat::Rfc3339);
+    assert_eq!(config.timestamp_position, TimestampPosition::Suffix);
+    assert!(!config.timestamp_subsecond);
+    assert!(config.sanitize_messages && config.escape_newlines && !config.escape_nul);
+    assert_eq!(
+        config.sampling_rules,
+        [("render".to_string(), Level::Debug, 0.25)]
+    );
+    assert!(config.to_string().contains(
+        " color=off scope=line theme=custom timestamp=Rfc3339 timestamp_position=Suffix \
+         subsecond=off sanitize=on escape_newlines=on escape_nul=off sampling=render=debug@0.25 \
+         terminator="
+    ));
+}
@@ -0,0 +1,33 @@
+mod common;
+
+use common::lock;
+use rust_telemetry::{
+    clear_module_levels, enabled, log_enabled, set_max_level, set_module_level, Level,
+};
+
+#[test]
+fn test_module_override_reflected_by_enabled() {
+    let _guard = lock();
+    set_max_level(Level::Warn);
+    set_module_level("db", Level::Debug);
+
+    assert!(enabled("db::pool", Level::Debug));
+    assert!(!log_enabled(Level::Debug));
+    assert!(!enabled("http", Level::Debug));
+    assert!(enabled("http", Level::Warn));
+
+    clear_module_levels();
+    set_max_level(Level::Debug);
+}
+
+#[test]
+fn test_quieter_override_disables_target() {
+    let _guard = lock();
+    set_module_level("noisy", Level::Error);
+
+    assert!(log_enabled(Level::Info));
+    assert!(!enabled("noisy", Level::Info));
+    assert!(!enabled("noisy", Level::Off));
+
+    clear_module_levels();
+}
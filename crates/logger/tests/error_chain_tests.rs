@@ -0,0 +1,69 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{log_error, ErrorChain, Level};
+use std::error::Error;
+use std::fmt;
+
+/// Error with a message and an optional source
+#[derive(Debug)]
+struct ChainError {
+    message: &'static str,
+    source: Option<Box<ChainError>>,
+}
+
+impl fmt::Display for ChainError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.message)
+    }
+}
+
+impl Error for ChainError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source.as_deref().map(|source| source as _)
+    }
+}
+
+/// `connection failed` caused by `timeout` caused by `dns failure`
+fn three_levels() -> ChainError {
+    ChainError {
+        message: "connection failed",
+        source: Some(Box::new(ChainError {
+            message: "timeout",
+            source: Some(Box::new(ChainError {
+                message: "dns failure",
+                source: None,
+            })),
+        })),
+    }
+}
+
+#[test]
+fn test_chain_joined_into_message() {
+    let err = three_levels();
+    let output = capture(|| log_error!(Level::Error, err));
+
+    assert_eq!(
+        output,
+        "00:00:00.000 errr connection failed: caused by: timeout: caused by: dns failure\n"
+    );
+}
+
+#[test]
+fn test_causes_as_fields() {
+    let err = three_levels();
+    let output = capture(|| log_error!(Level::Warn, err, ErrorChain::Fields));
+
+    assert_eq!(
+        output,
+        "00:00:00.000 warn connection failed cause_1=timeout cause_2=dns failure\n"
+    );
+}
+
+#[test]
+fn test_error_without_source() {
+    let err = std::io::Error::other("disk full");
+    let output = capture(|| log_error!(Level::Error, err));
+
+    assert_eq!(output, "00:00:00.000 errr disk full\n");
+}
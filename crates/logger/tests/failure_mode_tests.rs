@@ -0,0 +1,58 @@
+mod common;
+
+use common::lock;
+use rust_telemetry::{
+    clear_output, error, set_fatal_callback, set_output, set_write_failure_mode, WriteFailureMode,
+};
+use std::io::{self, Write};
+use std::panic;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// Writer that fails every write
+struct FailingWriter;
+
+impl Write for FailingWriter {
+    fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+        Err(io::Error::other("audit disk gone"))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_fatal_callback_after_threshold() {
+    let _guard = lock();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&calls);
+    set_fatal_callback(Box::new(move |err| {
+        assert_eq!(err.to_string(), "audit disk gone");
+        counter.fetch_add(1, Ordering::Relaxed);
+    }));
+    set_write_failure_mode(WriteFailureMode::Fatal, 3);
+    set_output(FailingWriter);
+
+    error("one");
+    error("two");
+    assert_eq!(calls.load(Ordering::Relaxed), 0);
+    error("three");
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+
+    set_write_failure_mode(WriteFailureMode::Continue, 0);
+    clear_output();
+}
+
+#[test]
+fn test_panic_mode_panics() {
+    let _guard = lock();
+    set_write_failure_mode(WriteFailureMode::Panic, 1);
+    set_output(FailingWriter);
+
+    let result = panic::catch_unwind(|| error("lost"));
+
+    set_write_failure_mode(WriteFailureMode::Continue, 0);
+    clear_output();
+    assert!(result.is_err());
+}
@@ -0,0 +1,58 @@
+mod common;
+
+use common::lock;
+use rust_telemetry::{
+    clear_failure_alert, clear_output, error, info, set_failure_alert, set_output,
+    set_stderr_fallback, stats,
+};
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// Writer that fails every write
+struct FailingWriter;
+
+impl Write for FailingWriter {
+    fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+        Err(io::Error::other("sink unavailable"))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_failing_writer_falls_back_and_alerts() {
+    let _guard = lock();
+    let alerts = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&alerts);
+
+    stats().reset();
+    set_output(FailingWriter);
+    set_stderr_fallback(true);
+    set_failure_alert(
+        3,
+        Box::new(move |err| {
+            assert_eq!(err.to_string(), "sink unavailable");
+            counter.fetch_add(1, Ordering::Relaxed);
+        }),
+    );
+
+    for i in 0..5 {
+        error(&format!("lost {i}"));
+    }
+
+    assert_eq!(stats().write_failures(), 5);
+    assert_eq!(stats().fallback_writes(), 5);
+    assert_eq!(alerts.load(Ordering::Relaxed), 1);
+
+    clear_failure_alert();
+    set_stderr_fallback(false);
+    info("not retried");
+    assert_eq!(stats().write_failures(), 6);
+    assert_eq!(stats().fallback_writes(), 5);
+
+    clear_output();
+    stats().reset();
+}
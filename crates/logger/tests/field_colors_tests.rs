@@ -0,0 +1,75 @@
+mod common;
+
+use common::{capture, capture_raw};
+use rust_telemetry::{
+    log, set_color_enabled, set_color_scope, set_format, set_theme, ColorScope, Field, Format,
+    Level, Record, Theme,
+};
+
+const FIELD_THEME: Theme = Theme {
+    field_key: "\x1b[2m",
+    field_value: "\x1b[36m",
+    ..Theme::DEFAULT
+};
+
+#[test]
+fn test_text_wraps_keys_and_values() {
+    let fields: [Field; 2] = [("status", &200), ("", &"bare")];
+    let output = capture_raw(|| {
+        set_theme(FIELD_THEME);
+        log(&Record::new(Level::Info, "done").with_fields(&fields));
+        set_theme(Theme::DEFAULT);
+    });
+
+    assert_eq!(
+        output,
+        "00:00:00.000 \x1b[32minfo\x1b[0m done \x1b[2mstatus\x1b[0m=\x1b[36m200\x1b[0m \x1b[36mbare\x1b[0m\n"
+    );
+}
+
+#[test]
+fn test_pretty_wraps_keys_and_values() {
+    let fields: [Field; 1] = [("status", &200)];
+    let output = capture_raw(|| {
+        set_theme(FIELD_THEME);
+        set_format(Format::Pretty);
+        log(&Record::new(Level::Info, "done").with_fields(&fields));
+        set_format(Format::Text);
+        set_theme(Theme::DEFAULT);
+    });
+
+    assert_eq!(
+        output,
+        "00:00:00.000 \x1b[32minfo\x1b[0m done\n    \x1b[2mstatus\x1b[0m: \x1b[36m200\x1b[0m\n"
+    );
+}
+
+#[test]
+fn test_whole_line_scope_skips_field_colors() {
+    let fields: [Field; 1] = [("status", &200)];
+    let output = capture_raw(|| {
+        set_theme(FIELD_THEME);
+        set_color_scope(ColorScope::WholeLine);
+        log(&Record::new(Level::Info, "done").with_fields(&fields));
+        set_theme(Theme::DEFAULT);
+    });
+
+    assert_eq!(output, "\x1b[32m00:00:00.000 info done status=200\x1b[0m\n");
+}
+
+#[test]
+fn test_color_disabled_writes_no_field_escapes() {
+    let fields: [Field; 1] = [("status", &200)];
+    let output = capture_raw(|| {
+        set_theme(FIELD_THEME);
+        set_color_enabled(false);
+        log(&Record::new(Level::Info, "done").with_fields(&fields));
+        set_theme(Theme::DEFAULT);
+    });
+
+    assert_eq!(output, "00:00:00.000 info done status=200\n");
+    assert_eq!(
+        capture(|| log(&Record::new(Level::Info, "done").with_fields(&fields))),
+        output
+    );
+}
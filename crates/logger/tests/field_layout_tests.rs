@@ -0,0 +1,59 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{
+    clear_field_layout, log, set_field_layout, Field, LayoutField, Level, Record,
+};
+
+#[test]
+fn test_level_before_timestamp() {
+    let fields: [Field; 1] = [("k", &"v")];
+    let output = capture(|| {
+        set_field_layout(&[
+            LayoutField::Level,
+            LayoutField::Timestamp,
+            LayoutField::Message,
+            LayoutField::Fields,
+        ]);
+        log(&Record::new(Level::Info, "msg").with_fields(&fields));
+        clear_field_layout();
+    });
+
+    assert_eq!(output, "info 00:00:00.000 msg k=v\n");
+}
+
+#[test]
+fn test_module_and_location_columns() {
+    let output = capture(|| {
+        set_field_layout(&[
+            LayoutField::Timestamp,
+            LayoutField::Module,
+            LayoutField::Location,
+            LayoutField::Level,
+            LayoutField::Message,
+            LayoutField::Fields,
+        ]);
+        log(&Record::new(Level::Warn, "disk low")
+            .with_target("app::disk")
+            .with_location("src/disk.rs", 42));
+        log(&Record::new(Level::Warn, "no metadata"));
+        clear_field_layout();
+    });
+
+    assert_eq!(
+        output,
+        "00:00:00.000 app::disk src/disk.rs:42 warn disk low\n00:00:00.000 warn no metadata\n"
+    );
+}
+
+#[test]
+fn test_clear_restores_default_layout() {
+    let output = capture(|| {
+        set_field_layout(&[LayoutField::Message, LayoutField::Level]);
+        log(&Record::new(Level::Info, "custom"));
+        clear_field_layout();
+        log(&Record::new(Level::Info, "default"));
+    });
+
+    assert_eq!(output, "custom info\n00:00:00.000 info default\n");
+}
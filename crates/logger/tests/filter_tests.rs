@@ -0,0 +1,48 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{
+    clear_module_levels, log, max_level, set_filter, set_max_level, Level, LevelFilter, Record,
+};
+
+#[test]
+fn test_level_from_str() {
+    assert_eq!("info".parse::<Level>(), Ok(Level::Info));
+    assert_eq!("WARN".parse::<Level>(), Ok(Level::Warn));
+    assert_eq!("warning".parse::<Level>(), Ok(Level::Warn));
+    assert!("loud".parse::<Level>().is_err());
+}
+
+#[test]
+fn test_filter_round_trip() {
+    let filter: LevelFilter = "warn, db=debug ,http::client=error".parse().unwrap();
+    assert_eq!(filter.default_level(), Some(Level::Warn));
+    assert_eq!(
+        filter.directives(),
+        [
+            ("db".to_string(), Level::Debug),
+            ("http::client".to_string(), Level::Error)
+        ]
+    );
+    assert_eq!(filter.to_string(), "warn,db=debug,http::client=error");
+    assert_eq!(filter.to_string().parse::<LevelFilter>(), Ok(filter));
+
+    assert!("db=loud".parse::<LevelFilter>().is_err());
+    assert!("=info".parse::<LevelFilter>().is_err());
+}
+
+#[test]
+fn test_apply_filter() {
+    let filter: LevelFilter = "warn,db=debug".parse().unwrap();
+    let output = capture(|| {
+        set_filter(&filter);
+        assert_eq!(max_level(), Level::Warn);
+        log(&Record::new(Level::Debug, "query").with_module_path("db::pool"));
+        log(&Record::new(Level::Info, "hidden").with_module_path("http"));
+        log(&Record::new(Level::Warn, "slow").with_module_path("http"));
+        clear_module_levels();
+        set_max_level(Level::Debug);
+    });
+
+    assert_eq!(output, "00:00:00.000 debg query\n00:00:00.000 warn slow\n");
+}
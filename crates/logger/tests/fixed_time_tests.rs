@@ -0,0 +1,47 @@
+mod common;
+
+use common::lock;
+use rust_telemetry::{
+    clear_clock, clear_output, info, set_fixed_time, set_output, set_timestamp_format,
+    MemoryWriter, TimestampFormat,
+};
+use std::time::{Duration, SystemTime};
+
+#[test]
+fn test_fixed_time_formats_as_rfc3339() {
+    let _guard = lock();
+    let buffer = MemoryWriter::new();
+    set_output(buffer.writer());
+    // 2024-02-29T23:59:58.250Z
+    set_fixed_time(SystemTime::UNIX_EPOCH + Duration::from_millis(1_709_251_198_250));
+
+    set_timestamp_format(TimestampFormat::Rfc3339);
+    info("leap day");
+    set_timestamp_format(TimestampFormat::TimeOfDay);
+    info("time of day");
+
+    clear_clock();
+    clear_output();
+
+    assert_eq!(
+        buffer.contents(),
+        "2024-02-29T23:59:58.250Z info leap day\n23:59:58.250 info time of day\n"
+    );
+}
+
+#[test]
+fn test_rfc3339_at_epoch() {
+    let _guard = lock();
+    let buffer = MemoryWriter::new();
+    set_output(buffer.writer());
+    set_fixed_time(SystemTime::UNIX_EPOCH);
+    set_timestamp_format(TimestampFormat::Rfc3339);
+
+    info("epoch");
+
+    set_timestamp_format(TimestampFormat::TimeOfDay);
+    clear_clock();
+    clear_output();
+
+    assert_eq!(buffer.contents(), "1970-01-01T00:00:00.000Z info epoch\n");
+}
@@ -0,0 +1,51 @@
+mod common;
+
+use common::lock;
+use rust_telemetry::{
+    clear_flush_interval, clear_output, clear_timestamp, info, set_flush_interval, set_output,
+    set_timestamp, MemoryWriter,
+};
+use std::io::BufWriter;
+use std::thread;
+use std::time::Duration;
+
+#[test]
+fn test_background_flush_without_explicit_flush() {
+    let _guard = lock();
+    let buffer = MemoryWriter::new();
+    set_output(BufWriter::new(buffer.clone()));
+    set_timestamp("00:00:00.000");
+    set_flush_interval(Duration::from_millis(100));
+
+    info("buffered");
+    assert_eq!(buffer.contents(), "");
+
+    thread::sleep(Duration::from_millis(400));
+    assert_eq!(buffer.contents(), "00:00:00.000 info buffered\n");
+
+    clear_flush_interval();
+    clear_timestamp();
+    clear_output();
+}
+
+#[test]
+fn test_clear_flush_interval_restores_line_flushing() {
+    let _guard = lock();
+    let buffer = MemoryWriter::new();
+    set_output(BufWriter::new(buffer.clone()));
+    set_timestamp("00:00:00.000");
+    set_flush_interval(Duration::from_secs(60));
+
+    info("pending");
+    clear_flush_interval();
+    assert_eq!(buffer.contents(), "00:00:00.000 info pending\n");
+
+    info("immediate");
+    assert_eq!(
+        buffer.contents(),
+        "00:00:00.000 info pending\n00:00:00.000 info immediate\n"
+    );
+
+    clear_timestamp();
+    clear_output();
+}
@@ -0,0 +1,40 @@
+mod common;
+
+use common::lock;
+use rust_telemetry::format::TextFormatter;
+use rust_telemetry::{
+    clear_timestamp, log_to, set_color_enabled, set_timestamp, Field, FmtWriter, Formatter, Level,
+    Record,
+};
+
+#[test]
+fn test_record_written_into_string() {
+    let _guard = lock();
+    let fields: [Field; 1] = [("user", &"bob")];
+    let mut writer = FmtWriter::new(String::new());
+    set_timestamp("00:00:00.000");
+    set_color_enabled(false);
+
+    log_to(
+        &mut writer,
+        &Record::new(Level::Info, "signed in").with_fields(&fields),
+    );
+    log_to(&mut writer, &Record::new(Level::Warn, "slow"));
+
+    set_color_enabled(true);
+    clear_timestamp();
+    assert_eq!(
+        writer.into_inner(),
+        "00:00:00.000 info signed in user=bob\n00:00:00.000 warn slow\n"
+    );
+}
+
+#[test]
+fn test_format_to_fmt_write() {
+    let _guard = lock();
+    let mut out = String::from("> ");
+    TextFormatter
+        .format_to(&Record::new(Level::Info, "ready"), "12:00:00.000", &mut out)
+        .unwrap();
+    assert_eq!(out, "> 12:00:00.000 \x1b[32minfo\x1b[0m ready");
+}
@@ -0,0 +1,36 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{log, set_format, Field, Format, Level, Record};
+
+#[test]
+fn test_pretty_multiline_layout() {
+    let fields: [Field; 2] = [("status", &500), ("path", &"/api/users")];
+    let output = capture(|| {
+        set_format(Format::Pretty);
+        log(&Record::new(Level::Error, "request failed")
+            .with_location("src/server.rs", 42)
+            .with_fields(&fields));
+        set_format(Format::Text);
+    });
+
+    assert_eq!(
+        output,
+        "00:00:00.000 errr request failed\n    status: 500\n    path: /api/users\n    at src/server.rs:42\n"
+    );
+}
+
+#[test]
+fn test_text_renders_fields_inline() {
+    let fields: [Field; 2] = [("status", &500), ("path", &"/api/users")];
+    let output = capture(|| {
+        log(&Record::new(Level::Error, "request failed")
+            .with_location("src/server.rs", 42)
+            .with_fields(&fields));
+    });
+
+    assert_eq!(
+        output,
+        "00:00:00.000 errr request failed status=500 path=/api/users\n"
+    );
+}
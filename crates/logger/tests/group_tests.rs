@@ -0,0 +1,49 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{info, pop_group, push_group, set_log_groups, with_group};
+
+#[test]
+fn test_nested_groups_indent() {
+    let output = capture(|| {
+        info("build");
+        push_group("compile");
+        info("parsing");
+        push_group("codegen");
+        info("emitting");
+        pop_group();
+        pop_group();
+        info("done");
+    });
+    assert_eq!(
+        output,
+        "00:00:00.000 info build\n\
+         00:00:00.000 info   parsing\n\
+         00:00:00.000 info     emitting\n\
+         00:00:00.000 info done\n"
+    );
+}
+
+#[test]
+fn test_group_labels_logged() {
+    let output = capture(|| {
+        set_log_groups(true);
+        with_group("test", || info("running"));
+        set_log_groups(false);
+    });
+    assert_eq!(
+        output,
+        "00:00:00.000 info test\n\
+         00:00:00.000 info   running\n\
+         00:00:00.000 info test done\n"
+    );
+}
+
+#[test]
+fn test_pop_without_group_is_noop() {
+    let output = capture(|| {
+        pop_group();
+        info("flat");
+    });
+    assert_eq!(output, "00:00:00.000 info flat\n");
+}
@@ -0,0 +1,58 @@
+mod common;
+
+use common::{capture, capture_raw};
+use rust_telemetry::format::GutterFormatter;
+use rust_telemetry::{
+    debug, error, info, log, set_format, set_formatter, warn, Field, Format, Level, Record,
+};
+
+#[test]
+fn test_gutter_for_each_level() {
+    let output = capture(|| {
+        set_format(Format::Gutter);
+        debug("d");
+        info("i");
+        warn("w");
+        error("e");
+        set_format(Format::Text);
+    });
+    assert_eq!(
+        output,
+        "│D│ 00:00:00.000 d\n\
+         │I│ 00:00:00.000 i\n\
+         │W│ 00:00:00.000 w\n\
+         │E│ 00:00:00.000 e\n"
+    );
+}
+
+#[test]
+fn test_color_applies_to_gutter_only() {
+    let fields: [Field; 1] = [("code", &7)];
+    let output = capture_raw(|| {
+        set_format(Format::Gutter);
+        log(&Record::new(Level::Error, "boom").with_fields(&fields));
+        set_format(Format::Text);
+    });
+    assert_eq!(output, "\x1b[31m│E│\x1b[0m 00:00:00.000 boom code=7\n");
+}
+
+#[test]
+fn test_custom_glyphs() {
+    let output = capture(|| {
+        set_formatter(Box::new(
+            GutterFormatter::new()
+                .glyph(Level::Warn, '!')
+                .glyph(Level::Error, '✗'),
+        ));
+        info("ok");
+        warn("careful");
+        error("failed");
+        set_format(Format::Text);
+    });
+    assert_eq!(
+        output,
+        "│I│ 00:00:00.000 ok\n\
+         │!│ 00:00:00.000 careful\n\
+         │✗│ 00:00:00.000 failed\n"
+    );
+}
@@ -0,0 +1,48 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{clear_on_record, debug, error, info, set_on_record, warn, Level};
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn test_on_record_counts_by_level() {
+    let counts = Arc::new(Mutex::new([0usize; 4]));
+    let sink = Arc::clone(&counts);
+
+    let output = capture(|| {
+        set_on_record(Box::new(move |record| {
+            let index = match record.level() {
+                Level::Debug => 0,
+                Level::Info => 1,
+                Level::Warn => 2,
+                Level::Error => 3,
+                Level::Off => unreachable!(),
+            };
+            sink.lock().unwrap()[index] += 1;
+        }));
+        debug("one");
+        info("two");
+        info("three");
+        error("four");
+        clear_on_record();
+        warn("after clear");
+    });
+
+    assert_eq!(*counts.lock().unwrap(), [1, 2, 0, 1]);
+    assert_eq!(output.lines().count(), 5);
+}
+
+#[test]
+fn test_on_record_panic_does_not_break_logging() {
+    let output = capture(|| {
+        set_on_record(Box::new(|_| panic!("boom")));
+        info("first");
+        info("second");
+        clear_on_record();
+    });
+
+    assert_eq!(
+        output,
+        "00:00:00.000 info first\n00:00:00.000 info second\n"
+    );
+}
@@ -0,0 +1,25 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{info, init, logged_before_init, set_warn_on_late_init};
+
+#[test]
+fn test_logging_before_init_is_reported() {
+    let output = capture(|| {
+        info("library warming up");
+        info("cache primed");
+        set_warn_on_late_init(true);
+        init();
+        info("after init");
+        init();
+    });
+
+    assert_eq!(logged_before_init(), 2);
+    assert_eq!(
+        output,
+        "00:00:00.000 info library warming up\n\
+         00:00:00.000 info cache primed\n\
+         00:00:00.000 warn telemetry: 2 records were logged before init\n\
+         00:00:00.000 info after init\n"
+    );
+}
@@ -0,0 +1,38 @@
+mod common;
+
+use common::{lock, RawWriter};
+use rust_telemetry::{
+    clear_internal_error_sink, clear_output, error, set_internal_error_sink, set_output,
+};
+use std::io::{self, Write};
+
+/// Writer that fails every write
+struct FailingWriter;
+
+impl Write for FailingWriter {
+    fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+        Err(io::Error::other("disk full"))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_write_error_reported_to_internal_sink() {
+    let _guard = lock();
+    let sink = RawWriter::default();
+    set_internal_error_sink(Box::new(sink.clone()));
+    set_output(FailingWriter);
+
+    error("lost");
+    error("also lost");
+
+    clear_output();
+    clear_internal_error_sink();
+    assert_eq!(
+        sink.contents(),
+        "rust-telemetry: failed to write log: disk full\n"
+    );
+}
@@ -0,0 +1,63 @@
+#![cfg(all(feature = "journald", unix))]
+
+use rust_telemetry::{
+    set_oversized_policy, JournaldWriter, Level, OversizedPolicy, Record, RecordWriter,
+};
+use std::os::unix::net::UnixDatagram;
+
+#[test]
+fn test_payload_frames_multiline_field() {
+    let fields: [rust_telemetry::Field; 2] = [("request-id", &42), ("body", &"a\nb")];
+    let record = Record::new(Level::Warn, "disk low")
+        .with_location("src/main.rs", 7)
+        .with_fields(&fields);
+
+    let mut expected =
+        b"PRIORITY=4\nMESSAGE=disk low\nCODE_FILE=src/main.rs\nCODE_LINE=7\n".to_vec();
+    expected.extend_from_slice(b"REQUEST_ID=42\n");
+    expected.extend_from_slice(b"BODY\n");
+    expected.extend_from_slice(&3u64.to_le_bytes());
+    expected.extend_from_slice(b"a\nb\n");
+
+    assert_eq!(JournaldWriter::payload(&record), expected);
+}
+
+#[test]
+fn test_writer_sends_datagram() {
+    let path = std::env::temp_dir().join(format!("rust-telemetry-journal-{}", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let receiver = UnixDatagram::bind(&path).unwrap();
+
+    let mut writer = JournaldWriter::with_socket(&path).unwrap();
+    let record = Record::new(Level::Error, "boom");
+    writer.write_record(&record, b"ignored\n").unwrap();
+
+    let mut buf = [0u8; 256];
+    let len = receiver.recv(&mut buf).unwrap();
+    assert_eq!(&buf[..len], b"PRIORITY=3\nMESSAGE=boom\n");
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn test_oversized_payload_is_split() {
+    let path = std::env::temp_dir().join(format!(
+        "rust-telemetry-journal-split-{}",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_file(&path);
+    let receiver = UnixDatagram::bind(&path).unwrap();
+
+    let mut writer = JournaldWriter::with_socket(&path).unwrap().max_payload(25);
+    set_oversized_policy(OversizedPolicy::Split);
+    writer
+        .write_record(&Record::new(Level::Info, "0123456789"), b"")
+        .unwrap();
+    set_oversized_policy(OversizedPolicy::Truncate);
+
+    let mut buf = [0u8; 256];
+    let first = receiver.recv(&mut buf).unwrap();
+    assert_eq!(&buf[..first], b"PRIORITY=6\nMESSAGE=01234\n");
+    let second = receiver.recv(&mut buf).unwrap();
+    assert_eq!(&buf[..second], b"PRIORITY=6\nMESSAGE=56789\n");
+    let _ = std::fs::remove_file(&path);
+}
@@ -0,0 +1,46 @@
+mod common;
+
+use common::lock;
+use rust_telemetry::{
+    clear_output, clear_timestamp, error, set_output, set_timestamp, FileWriter, Level,
+    MemoryWriter,
+};
+
+#[test]
+fn test_color_file_writer_keeps_escapes() {
+    let _guard = lock();
+    let dir = std::env::temp_dir();
+    let colored = dir.join(format!("rust-telemetry-color-{}.log", std::process::id()));
+    let plain = dir.join(format!("rust-telemetry-plain-{}.log", std::process::id()));
+    set_timestamp("00:00:00.000");
+
+    set_output(FileWriter::with_color(&colored, true).unwrap());
+    error("boom");
+    set_output(FileWriter::new(&plain).unwrap());
+    error("boom");
+    clear_output();
+    clear_timestamp();
+
+    let colored_contents = std::fs::read_to_string(&colored).unwrap();
+    let plain_contents = std::fs::read_to_string(&plain).unwrap();
+    let _ = std::fs::remove_file(colored);
+    let _ = std::fs::remove_file(plain);
+
+    assert_eq!(colored_contents, "00:00:00.000 \x1b[31merrr\x1b[0m boom\n");
+    assert_eq!(plain_contents, "00:00:00.000 errr boom\n");
+}
+
+#[test]
+fn test_color_memory_writer_keeps_escapes() {
+    let _guard = lock();
+    let buffer = MemoryWriter::with_color();
+    set_output(buffer.writer());
+    set_timestamp("00:00:00.000");
+    error("boom");
+    clear_timestamp();
+    clear_output();
+
+    assert_eq!(buffer.contents(), "00:00:00.000 \x1b[31merrr\x1b[0m boom\n");
+    let lines: Vec<_> = buffer.iter_lines().collect();
+    assert_eq!(lines, vec![(Some(Level::Error), "boom".to_string())]);
+}
@@ -0,0 +1,36 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{log_kv, set_format, Field, Format, Level};
+
+/// Fields built at runtime, as a library without macros would
+fn log_request(format: Format) {
+    let status = 200;
+    let path = String::from("/health");
+    let fields: Vec<Field> = vec![("path", &path), ("status", &status)];
+    set_format(format);
+    log_kv(Level::Info, "request", &fields);
+}
+
+#[test]
+fn test_log_kv_text() {
+    let output = capture(|| log_request(Format::Text));
+
+    assert_eq!(
+        output,
+        "00:00:00.000 info request path=/health status=200\n"
+    );
+}
+
+#[test]
+fn test_log_kv_pretty() {
+    let output = capture(|| {
+        log_request(Format::Pretty);
+        set_format(Format::Text);
+    });
+
+    assert_eq!(
+        output,
+        "00:00:00.000 info request\n    path: /health\n    status: 200\n"
+    );
+}
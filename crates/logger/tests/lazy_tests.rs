@@ -0,0 +1,31 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{log_lazy, set_max_level, Level};
+use std::cell::Cell;
+
+#[test]
+fn test_log_lazy_skips_closure_when_disabled() {
+    let called = Cell::new(false);
+
+    let output = capture(|| {
+        set_max_level(Level::Info);
+        log_lazy(Level::Debug, || {
+            called.set(true);
+            "expensive".to_string()
+        });
+        set_max_level(Level::Debug);
+    });
+
+    assert!(!called.get());
+    assert_eq!(output, "");
+}
+
+#[test]
+fn test_log_lazy_builds_message_when_enabled() {
+    let output = capture(|| {
+        log_lazy(Level::Info, || format!("{} + {} = {}", 1, 2, 1 + 2));
+    });
+
+    assert_eq!(output, "00:00:00.000 info 1 + 2 = 3\n");
+}
@@ -0,0 +1,19 @@
+use rust_telemetry::{Level, LEVEL_COUNT};
+
+#[test]
+fn test_all_yields_loggable_levels_in_order() {
+    assert_eq!(
+        Level::all(),
+        &[Level::Debug, Level::Info, Level::Warn, Level::Error]
+    );
+    assert_eq!(Level::all().len(), LEVEL_COUNT);
+    assert!(!Level::all().contains(&Level::Off));
+    assert!(Level::all().windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+fn test_all_round_trips_through_parse() {
+    for level in Level::all() {
+        assert_eq!(level.to_string().parse::<Level>(), Ok(*level));
+    }
+}
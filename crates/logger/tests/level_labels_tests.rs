@@ -0,0 +1,47 @@
+mod common;
+
+use common::capture_raw;
+use rust_telemetry::{debug, error, info, warn, Level, MemoryWriter, Theme};
+use std::io::Write;
+
+#[test]
+fn test_function_output_uses_level_color_and_label() {
+    let output = capture_raw(|| {
+        debug("m");
+        info("m");
+        warn("m");
+        error("m");
+    });
+
+    let expected: String = Level::all()
+        .iter()
+        .map(|level| {
+            format!(
+                "00:00:00.000 {}{}{} m\n",
+                level.color(),
+                level.as_str(),
+                Theme::DEFAULT.reset
+            )
+        })
+        .collect();
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn test_labels_parse_back() {
+    let buffer = MemoryWriter::new();
+    for level in Level::all() {
+        writeln!(buffer.writer(), "00:00:00.000 {} m", level.as_str()).unwrap();
+    }
+
+    let levels: Vec<_> = buffer.iter_lines().map(|(level, _)| level).collect();
+    assert_eq!(
+        levels,
+        [
+            Some(Level::Debug),
+            Some(Level::Info),
+            Some(Level::Warn),
+            Some(Level::Error)
+        ]
+    );
+}
@@ -0,0 +1,40 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{clear_level_names, error, info, set_level_names, warn, Level};
+
+#[test]
+fn test_custom_names_render() {
+    let output = capture(|| {
+        set_level_names(&[(Level::Warn, "WARNING"), (Level::Error, "CRITICAL")]);
+        info("started");
+        warn("disk low");
+        error("disk full");
+        clear_level_names();
+    });
+    assert_eq!(
+        output,
+        "00:00:00.000 info started\n\
+         00:00:00.000 WARNING disk low\n\
+         00:00:00.000 CRITICAL disk full\n"
+    );
+}
+
+#[test]
+fn test_parsing_accepts_canonical_names() {
+    let _guard = common::lock();
+    set_level_names(&[(Level::Warn, "WARNING")]);
+    assert_eq!("WARN".parse(), Ok(Level::Warn));
+    assert_eq!("warn".parse(), Ok(Level::Warn));
+    clear_level_names();
+}
+
+#[test]
+fn test_clear_restores_defaults() {
+    let output = capture(|| {
+        set_level_names(&[(Level::Warn, "WARNING")]);
+        clear_level_names();
+        warn("disk low");
+    });
+    assert_eq!(output, "00:00:00.000 warn disk low\n");
+}
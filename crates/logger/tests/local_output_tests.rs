@@ -0,0 +1,62 @@
+use rust_telemetry::{
+    clear_output_local, info, set_output_local, warn, with_captured_output, MemoryWriter,
+};
+use std::{panic, thread};
+
+#[test]
+fn test_threads_capture_independently() {
+    let handles: Vec<_> = ["alpha", "beta"]
+        .into_iter()
+        .map(|name| {
+            thread::spawn(move || {
+                with_captured_output(|| {
+                    for i in 0..50 {
+                        info(&format!("{name} {i}"));
+                    }
+                })
+            })
+        })
+        .collect();
+
+    for (handle, name) in handles.into_iter().zip(["alpha", "beta"]) {
+        let output = handle.join().unwrap();
+        assert_eq!(output.lines().count(), 50);
+        assert!(output
+            .lines()
+            .all(|line| line.contains(&format!("info {name} "))));
+    }
+}
+
+#[test]
+fn test_nested_capture_restores_outer() {
+    let outer = with_captured_output(|| {
+        info("outer before");
+        let inner = with_captured_output(|| warn("inner"));
+        assert!(inner.ends_with("warn inner\n"));
+        info("outer after");
+    });
+
+    assert_eq!(outer.lines().count(), 2);
+    assert!(!outer.contains("inner"));
+}
+
+#[test]
+fn test_set_output_local() {
+    let buffer = MemoryWriter::new();
+    set_output_local(buffer.writer());
+    info("local");
+    clear_output_local();
+
+    assert!(buffer.contents().ends_with("info local\n"));
+}
+
+#[test]
+fn test_capture_is_restored_after_panic() {
+    let outer = with_captured_output(|| {
+        let result = panic::catch_unwind(|| with_captured_output(|| panic!("boom")));
+        assert!(result.is_err());
+        info("after panic");
+    });
+
+    assert!(outer.ends_with("info after panic\n"));
+}
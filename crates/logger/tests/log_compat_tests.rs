@@ -0,0 +1,69 @@
+#![cfg(feature = "log-compat")]
+
+mod common;
+
+use common::capture;
+use rust_telemetry::{
+    clear_module_levels, init_log_compat, set_filter, set_max_level, set_module_level, Level,
+};
+use std::sync::Once;
+
+/// Install the bridge once per test binary (`log` accepts a single logger)
+fn install() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| init_log_compat().unwrap());
+}
+
+#[test]
+fn test_levels_stay_in_sync_with_log_facade() {
+    let output = capture(|| {
+        install();
+        assert_eq!(log::max_level(), log::LevelFilter::Trace);
+
+        set_max_level(Level::Warn);
+        assert_eq!(log::max_level(), log::LevelFilter::Warn);
+        log::info!("filtered by the facade");
+        log::warn!(target: "db", "slow query");
+
+        set_max_level(Level::Info);
+        assert_eq!(log::max_level(), log::LevelFilter::Info);
+        log::info!("now visible");
+
+        set_max_level(Level::Debug);
+        assert_eq!(log::max_level(), log::LevelFilter::Trace);
+        log::trace!("trace folds into debug");
+    });
+
+    assert_eq!(
+        output,
+        "00:00:00.000 warn slow query\n\
+         00:00:00.000 info now visible\n\
+         00:00:00.000 debg trace folds into debug\n"
+    );
+}
+
+#[test]
+fn test_target_overrides_reach_the_log_facade() {
+    let output = capture(|| {
+        install();
+        set_filter(&"warn,db=debug".parse().unwrap());
+        assert_eq!(log::max_level(), log::LevelFilter::Trace);
+        log::debug!(target: "db", "query plan");
+        log::debug!(target: "http", "filtered by the bridge");
+
+        clear_module_levels();
+        assert_eq!(log::max_level(), log::LevelFilter::Warn);
+        set_module_level("cache", Level::Info);
+        assert_eq!(log::max_level(), log::LevelFilter::Info);
+        log::info!(target: "cache", "cache miss");
+
+        clear_module_levels();
+        set_max_level(Level::Debug);
+    });
+
+    assert_eq!(
+        output,
+        "00:00:00.000 debg query plan\n\
+         00:00:00.000 info cache miss\n"
+    );
+}
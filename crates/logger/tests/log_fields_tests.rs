@@ -0,0 +1,45 @@
+mod common;
+
+use common::{capture, compiled_in};
+use rust_telemetry::{log_fields, set_max_level, Level};
+
+struct Config {
+    host: &'static str,
+    port: u16,
+    verbose: bool,
+}
+
+#[test]
+fn test_logs_named_fields() {
+    if !compiled_in(Level::Info) {
+        return;
+    }
+    let config = Config {
+        host: "localhost",
+        port: 8080,
+        verbose: true,
+    };
+    let output = capture(|| log_fields!(Level::Info, config { host, port }));
+
+    assert_eq!(
+        output,
+        "00:00:00.000 info config host=localhost port=8080\n"
+    );
+    assert!(config.verbose);
+}
+
+#[test]
+fn test_below_max_level_is_skipped() {
+    let config = Config {
+        host: "localhost",
+        port: 8080,
+        verbose: false,
+    };
+    let output = capture(|| {
+        set_max_level(Level::Warn);
+        log_fields!(Level::Info, config { verbose });
+        set_max_level(Level::Debug);
+    });
+
+    assert_eq!(output, "");
+}
@@ -0,0 +1,35 @@
+mod common;
+
+use common::lock;
+use rust_telemetry::{
+    clear_output, clear_timestamp, log_to, set_max_level, set_output, set_timestamp, Level,
+    MemoryWriter, Record,
+};
+
+#[test]
+fn test_log_to_writes_only_to_target() {
+    let _guard = lock();
+    let global = MemoryWriter::new();
+    let mut target = MemoryWriter::new();
+    set_output(global.writer());
+    set_timestamp("00:00:00.000");
+
+    log_to(&mut target, &Record::new(Level::Warn, "audit entry"));
+
+    clear_timestamp();
+    clear_output();
+    assert_eq!(target.contents(), "00:00:00.000 warn audit entry\n");
+    assert_eq!(global.contents(), "");
+}
+
+#[test]
+fn test_log_to_respects_level() {
+    let _guard = lock();
+    let mut target = MemoryWriter::new();
+    set_max_level(Level::Warn);
+
+    log_to(&mut target, &Record::new(Level::Info, "filtered"));
+
+    set_max_level(Level::Debug);
+    assert_eq!(target.contents(), "");
+}
@@ -0,0 +1,19 @@
+#![cfg(all(feature = "android", target_os = "android"))]
+
+use rust_telemetry::{Level, LogcatWriter, Record, RecordWriter};
+
+#[test]
+fn test_priority_mapping() {
+    assert_eq!(LogcatWriter::priority(Level::Debug), 3);
+    assert_eq!(LogcatWriter::priority(Level::Info), 4);
+    assert_eq!(LogcatWriter::priority(Level::Warn), 5);
+    assert_eq!(LogcatWriter::priority(Level::Error), 6);
+}
+
+#[test]
+fn test_write_record() {
+    let mut writer = LogcatWriter::new("rust-telemetry");
+    let record = Record::new(Level::Info, "hello from rust");
+
+    writer.write_record(&record, b"").unwrap();
+}
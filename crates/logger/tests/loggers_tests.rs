@@ -1,19 +1,15 @@
+mod common;
+
 use insta::assert_snapshot;
-use rust_telemetry::{
-    clear_output, clear_timestamp, debug, error, info, set_output, set_timestamp, warn,
-    MemoryWriter,
-};
+use rust_telemetry::{debug, error, info, warn};
 
 /// Run a block with a memory writer and fixed timestamp, then assert snapshot
 macro_rules! capture {
     ($($body:tt)*) => {{
-        let buffer = MemoryWriter::new();
-        set_output(buffer.writer());
-        set_timestamp("00:00:00.000");
-        $($body)*
-        assert_snapshot!(buffer.contents());
-        clear_timestamp();
-        clear_output();
+        let output = common::capture(|| {
+            $($body)*
+        });
+        assert_snapshot!(output);
     }};
 }
 
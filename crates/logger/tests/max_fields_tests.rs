@@ -0,0 +1,39 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{log, set_format, set_max_fields, Field, Format, Level, Record};
+
+#[test]
+fn test_extra_fields_replaced_by_marker() {
+    let fields: [Field; 5] = [("a", &1), ("b", &2), ("c", &3), ("d", &4), ("e", &5)];
+    let output = capture(|| {
+        set_max_fields(2);
+        log(&Record::new(Level::Info, "busy").with_fields(&fields));
+        set_max_fields(usize::MAX);
+    });
+    assert_eq!(output, "00:00:00.000 info busy a=1 b=2 …(+3 more)\n");
+}
+
+#[test]
+fn test_within_limit_unchanged() {
+    let fields: [Field; 2] = [("a", &1), ("b", &2)];
+    let output = capture(|| {
+        set_max_fields(2);
+        log(&Record::new(Level::Info, "calm").with_fields(&fields));
+        set_max_fields(usize::MAX);
+    });
+    assert_eq!(output, "00:00:00.000 info calm a=1 b=2\n");
+}
+
+#[test]
+fn test_marker_in_pretty_format() {
+    let fields: [Field; 3] = [("a", &1), ("b", &2), ("c", &3)];
+    let output = capture(|| {
+        set_format(Format::Pretty);
+        set_max_fields(1);
+        log(&Record::new(Level::Info, "busy").with_fields(&fields));
+        set_max_fields(usize::MAX);
+        set_format(Format::Text);
+    });
+    assert_eq!(output, "00:00:00.000 info busy\n    a: 1\n    …(+2 more)\n");
+}
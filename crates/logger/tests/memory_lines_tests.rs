@@ -0,0 +1,45 @@
+mod common;
+
+use common::lock;
+use rust_telemetry::{clear_output, error, info, set_output, set_show_seq, Level, MemoryWriter};
+use std::io::Write;
+
+#[test]
+fn test_iter_lines_parses_levels() {
+    let _guard = lock();
+    let buffer = MemoryWriter::new();
+    set_output(buffer.writer());
+
+    info("listening");
+    set_show_seq(true);
+    error("boom: disk full");
+    set_show_seq(false);
+    clear_output();
+
+    let lines: Vec<_> = buffer.iter_lines().collect();
+    assert_eq!(lines[0], (Some(Level::Info), "listening".to_string()));
+    assert_eq!(
+        lines[1],
+        (Some(Level::Error), "boom: disk full".to_string())
+    );
+    assert!(buffer
+        .iter_lines()
+        .any(|(l, m)| l == Some(Level::Error) && m.contains("boom")));
+}
+
+#[test]
+fn test_iter_lines_unrecognized_and_bracketed() {
+    let mut buffer = MemoryWriter::new();
+    buffer
+        .write_all(b"[WARN] from another logger\nplain text line\n")
+        .unwrap();
+
+    let lines: Vec<_> = buffer.iter_lines().collect();
+    assert_eq!(
+        lines,
+        [
+            (Some(Level::Warn), "from another logger".to_string()),
+            (None, "plain text line".to_string()),
+        ]
+    );
+}
@@ -0,0 +1,51 @@
+mod common;
+
+use common::lock;
+use rust_telemetry::{
+    clear_clock, clear_output, info, set_clock, set_output, set_timestamp_format, MemoryWriter,
+    MockClock, TimestampFormat,
+};
+use std::time::{Duration, SystemTime};
+
+/// Log one line just before and one just after the first midnight after the epoch
+fn across_midnight(format: TimestampFormat) -> String {
+    let _guard = lock();
+    let buffer = MemoryWriter::new();
+    let clock = MockClock::new(SystemTime::UNIX_EPOCH + Duration::from_millis(86_399_500));
+    set_output(buffer.writer());
+    set_clock(Box::new(clock.clone()));
+    set_timestamp_format(format);
+
+    info("before");
+    clock.advance(Duration::from_secs(1));
+    info("after");
+
+    set_timestamp_format(TimestampFormat::TimeOfDay);
+    clear_clock();
+    clear_output();
+    buffer.contents()
+}
+
+#[test]
+fn test_time_of_day_wraps_at_midnight() {
+    assert_eq!(
+        across_midnight(TimestampFormat::TimeOfDay),
+        "23:59:59.500 info before\n00:00:00.500 info after\n"
+    );
+}
+
+#[test]
+fn test_date_time_crosses_midnight() {
+    assert_eq!(
+        across_midnight(TimestampFormat::DateTime),
+        "1970-01-01 23:59:59.500 info before\n1970-01-02 00:00:00.500 info after\n"
+    );
+}
+
+#[test]
+fn test_rfc3339_crosses_midnight() {
+    assert_eq!(
+        across_midnight(TimestampFormat::Rfc3339),
+        "1970-01-01T23:59:59.500Z info before\n1970-01-02T00:00:00.500Z info after\n"
+    );
+}
@@ -0,0 +1,51 @@
+//! Run with `cargo test -p rust-telemetry --no-default-features --test no_std_tests`
+
+#![cfg(not(feature = "std"))]
+
+use rust_telemetry::format::TextFormatter;
+use rust_telemetry::{set_max_level, set_sink, Formatter, Level, OwnedRecord, Record, Sink};
+use std::sync::Mutex;
+
+/// Sink collecting lines in memory
+struct VecSink(Mutex<Vec<String>>);
+
+impl Sink for VecSink {
+    fn write_line(&self, _record: &Record, line: &str) {
+        self.0.lock().unwrap().push(line.to_string());
+    }
+}
+
+static SINK: VecSink = VecSink(Mutex::new(Vec::new()));
+
+#[test]
+fn test_core_logs_through_custom_sink() {
+    set_sink(&SINK).unwrap();
+    assert!(set_sink(&SINK).is_err());
+
+    set_max_level(Level::Info);
+    rust_telemetry::debug!("hidden");
+    rust_telemetry::info!("answer is {}", 42);
+    rust_telemetry::warn!(target: "audit", "user {} removed", "bob");
+    OwnedRecord::new(Level::Error, "owned")
+        .with_field("code", 7)
+        .log();
+
+    let lines = SINK.0.lock().unwrap();
+    assert_eq!(
+        *lines,
+        [
+            "\x1b[32minfo\x1b[0m answer is 42",
+            "\x1b[33mwarn\x1b[0m user bob removed",
+            "\x1b[31merrr\x1b[0m owned code=7",
+        ]
+    );
+}
+
+#[test]
+fn test_format_to_fmt_write() {
+    let mut out = String::new();
+    TextFormatter
+        .format_to(&Record::new(Level::Warn, "low memory"), "", &mut out)
+        .unwrap();
+    assert_eq!(out, "\x1b[33mwarn\x1b[0m low memory");
+}
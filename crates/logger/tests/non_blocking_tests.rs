@@ -0,0 +1,205 @@
+mod common;
+
+use common::lock;
+use rust_telemetry::{
+    clear_output, clear_timestamp, info, set_color_enabled, set_output, set_timestamp,
+    MemoryWriter, NonBlockingWriter,
+};
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::Duration;
+
+/// Writer that blocks each write until the test releases it
+struct GatedWriter {
+    gate: Mutex<Receiver<()>>,
+    output: MemoryWriter,
+}
+
+impl Write for GatedWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let _ = self.gate.lock().unwrap().recv();
+        self.output.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_full_queue_drops_and_reports() {
+    let _guard = lock();
+    let (release, gate) = mpsc::channel();
+    let output = MemoryWriter::new();
+    let writer = NonBlockingWriter::new(
+        GatedWriter {
+            gate: Mutex::new(gate),
+            output: output.clone(),
+        },
+        2,
+    )
+    .report_interval(Duration::ZERO);
+
+    let last_seen = Arc::new(AtomicU64::new(0));
+    let seen = Arc::clone(&last_seen);
+    writer.set_overflow_callback(Box::new(move |total| seen.store(total, Ordering::Relaxed)));
+
+    set_output(writer.clone());
+    set_timestamp("00:00:00.000");
+    set_color_enabled(false);
+
+    // One line is held by the blocked thread, two fill the queue
+    for i in 0..8 {
+        info(&format!("line {i}"));
+    }
+    assert!(writer.dropped_count() > 0);
+
+    // Once released, the background thread reports the drops
+    let dropped = writer.dropped_count();
+    let releaser = std::thread::spawn(move || {
+        for _ in 0..64 {
+            let _ = release.send(());
+        }
+    });
+    writer.drain();
+    info("after release");
+    writer.drain();
+    releaser.join().unwrap();
+    assert_eq!(last_seen.load(Ordering::Relaxed), dropped);
+
+    set_color_enabled(true);
+    clear_timestamp();
+    clear_output();
+
+    let contents = output.contents();
+    assert!(contents.starts_with("00:00:00.000 info line 0\n"));
+    assert!(contents.contains(&format!(
+        "00:00:00.000 warn dropped {dropped} log records due to backpressure\n"
+    )));
+    assert!(contents.ends_with("00:00:00.000 info after release\n"));
+}
+
+/// Writer that blocks writes while paused
+#[derive(Clone, Default)]
+struct PausableWriter {
+    paused: Arc<(Mutex<bool>, Condvar)>,
+    output: MemoryWriter,
+}
+
+impl PausableWriter {
+    fn set_paused(&self, paused: bool) {
+        *self.paused.0.lock().unwrap() = paused;
+        self.paused.1.notify_all();
+    }
+}
+
+impl Write for PausableWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let (paused, resumed) = &*self.paused;
+        let _guard = resumed
+            .wait_while(paused.lock().unwrap(), |paused| *paused)
+            .unwrap();
+        self.output.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Log `count` lines through `writer` while its background thread is paused
+///
+/// Returns how many of them were dropped.
+fn overflow(writer: &NonBlockingWriter, inner: &PausableWriter, count: usize) -> u64 {
+    let before = writer.dropped_count();
+    inner.set_paused(true);
+    for i in 0..count {
+        info(&format!("line {i}"));
+    }
+    inner.set_paused(false);
+    writer.dropped_count() - before
+}
+
+/// A `NonBlockingWriter` with capacity 1 over a pausable writer
+fn pausable(report_interval: Duration) -> (NonBlockingWriter, PausableWriter) {
+    let inner = PausableWriter::default();
+    let writer = NonBlockingWriter::new(inner.clone(), 1).report_interval(report_interval);
+    (writer, inner)
+}
+
+#[test]
+fn test_first_drops_are_reported_without_waiting_an_interval() {
+    let _guard = lock();
+    let (writer, inner) = pausable(Duration::from_secs(3600));
+    set_output(writer.clone());
+    set_timestamp("00:00:00.000");
+    set_color_enabled(false);
+
+    let dropped = overflow(&writer, &inner, 6);
+    let warning = format!("warn dropped {dropped} log records due to backpressure");
+    // No drain: the thread writes the warning as soon as it catches up
+    let deadline = std::time::Instant::now() + Duration::from_secs(5);
+    while !inner.output.contents().contains(&warning) && std::time::Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(1));
+    }
+
+    set_color_enabled(true);
+    clear_timestamp();
+    clear_output();
+    assert!(dropped > 0);
+    assert!(inner.output.contents().contains(&warning));
+}
+
+#[test]
+fn test_drain_and_shutdown_report_outstanding_drops() {
+    let _guard = lock();
+    let (writer, inner) = pausable(Duration::from_secs(3600));
+    set_output(writer.clone());
+    set_timestamp("00:00:00.000");
+    set_color_enabled(false);
+
+    // The first burst is reported at once; within the interval, drain and shutdown report the rest
+    let first = overflow(&writer, &inner, 6);
+    writer.drain();
+    let second = overflow(&writer, &inner, 6);
+    writer.drain();
+    let third = overflow(&writer, &inner, 6);
+    let finished = writer.shutdown(Duration::from_secs(5));
+
+    set_color_enabled(true);
+    clear_timestamp();
+    clear_output();
+    assert!(finished);
+    let reported: Vec<u64> = inner
+        .output
+        .contents()
+        .lines()
+        .filter_map(|line| line.strip_prefix("00:00:00.000 warn dropped "))
+        .map(|rest| rest.split(' ').next().unwrap().parse().unwrap())
+        .collect();
+    assert!(first > 0 && second > 0 && third > 0);
+    assert_eq!(reported, [first, second, third]);
+}
+
+#[test]
+fn test_overflow_callback_may_log() {
+    let _guard = lock();
+    let (writer, inner) = pausable(Duration::ZERO);
+    writer.set_overflow_callback(Box::new(|total| {
+        info(&format!("overflow callback {total}"))
+    }));
+    set_output(writer.clone());
+    set_timestamp("00:00:00.000");
+    set_color_enabled(false);
+
+    let dropped = overflow(&writer, &inner, 6);
+    writer.drain();
+
+    set_color_enabled(true);
+    clear_timestamp();
+    clear_output();
+    assert!(dropped > 0);
+    assert!(inner.output.contents().contains("overflow callback"));
+}
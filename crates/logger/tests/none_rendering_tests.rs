@@ -0,0 +1,102 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{
+    log, set_none_rendering, set_text_field_style, Field, Level, NoneRendering, OptionField,
+    Record, TextFieldStyle,
+};
+use std::cell::Cell;
+use std::fmt;
+
+fn render(mode: NoneRendering, style: TextFieldStyle) -> String {
+    let user = OptionField(Some("alice"));
+    let session = OptionField(None::<u64>);
+    let fields: [Field; 2] = [("user", user.value()), ("session", session.value())];
+    capture(|| {
+        set_none_rendering(mode);
+        set_text_field_style(style);
+        log(&Record::new(Level::Info, "login").with_fields(&fields));
+        set_text_field_style(TextFieldStyle::KeyValue);
+        set_none_rendering(NoneRendering::Literal);
+    })
+}
+
+#[test]
+fn test_literal_renders_none() {
+    assert_eq!(
+        render(NoneRendering::Literal, TextFieldStyle::KeyValue),
+        "00:00:00.000 info login user=alice session=none\n"
+    );
+    assert_eq!(
+        render(NoneRendering::Literal, TextFieldStyle::Json),
+        "00:00:00.000 info login {\"user\":\"alice\",\"session\":\"none\"}\n"
+    );
+}
+
+#[test]
+fn test_omit_drops_none_field() {
+    assert_eq!(
+        render(NoneRendering::Omit, TextFieldStyle::KeyValue),
+        "00:00:00.000 info login user=alice\n"
+    );
+    assert_eq!(
+        render(NoneRendering::Omit, TextFieldStyle::Json),
+        "00:00:00.000 info login {\"user\":\"alice\"}\n"
+    );
+}
+
+#[test]
+fn test_null_is_empty_in_text_and_null_in_json() {
+    assert_eq!(
+        render(NoneRendering::Null, TextFieldStyle::KeyValue),
+        "00:00:00.000 info login user=alice session=\n"
+    );
+    assert_eq!(
+        render(NoneRendering::Null, TextFieldStyle::Json),
+        "00:00:00.000 info login {\"user\":\"alice\",\"session\":null}\n"
+    );
+}
+
+#[test]
+fn test_omit_keeps_some_values_whatever_their_text() {
+    let empty = OptionField(Some(""));
+    let marker = "\0none\0";
+    let fields: [Field; 2] = [("empty", empty.value()), ("marker", &marker)];
+    let out = capture(|| {
+        set_none_rendering(NoneRendering::Omit);
+        log(&Record::new(Level::Info, "login").with_fields(&fields));
+        set_none_rendering(NoneRendering::Literal);
+    });
+    assert!(out.contains(" empty= "), "{out:?}");
+    assert!(out.contains(" marker="), "{out:?}");
+}
+
+/// Display impl counting how often it is rendered
+struct Counted<'a>(&'a Cell<usize>);
+
+impl fmt::Display for Counted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.set(self.0.get() + 1);
+        f.write_str("x")
+    }
+}
+
+#[test]
+fn test_omit_does_not_render_values_again() {
+    let renders = |mode| {
+        let count = Cell::new(0);
+        let value = OptionField(Some(Counted(&count)));
+        let fields: [Field; 1] = [("value", value.value())];
+        capture(|| {
+            set_none_rendering(mode);
+            log(&Record::new(Level::Info, "tick").with_fields(&fields));
+            set_none_rendering(NoneRendering::Literal);
+        });
+        count.get()
+    };
+
+    assert_eq!(
+        renders(NoneRendering::Omit),
+        renders(NoneRendering::Literal)
+    );
+}
@@ -0,0 +1,49 @@
+mod common;
+
+use common::{capture, compiled_in};
+use rust_telemetry::{info, log_kv, set_escape_nul, set_sanitize_messages, Field, Level};
+
+#[test]
+fn test_nul_in_message_is_escaped() {
+    if !compiled_in(Level::Info) {
+        return;
+    }
+    let output = capture(|| info!("user\0admin"));
+
+    assert_eq!(output, "00:00:00.000 info user\\0admin\n");
+    assert!(!output.contains('\0'));
+}
+
+#[test]
+fn test_nul_in_field_value_is_escaped() {
+    let fields: [Field; 1] = [("name", &"a\0b")];
+    let output = capture(|| log_kv(Level::Info, "login", &fields));
+
+    assert_eq!(output, "00:00:00.000 info login name=a\\0b\n");
+}
+
+#[test]
+fn test_sanitize_escapes_other_controls_in_fields() {
+    let fields: [Field; 1] = [("raw", &"\x1b[31m\0")];
+    let output = capture(|| {
+        set_sanitize_messages(true);
+        log_kv(Level::Info, "bell\x07", &fields);
+        set_sanitize_messages(false);
+    });
+
+    assert_eq!(output, "00:00:00.000 info bell\\x07 raw=\\x1b[31m\\0\n");
+}
+
+#[test]
+fn test_escaping_can_be_disabled() {
+    if !compiled_in(Level::Info) {
+        return;
+    }
+    let output = capture(|| {
+        set_escape_nul(false);
+        info!("raw\0nul");
+        set_escape_nul(true);
+    });
+
+    assert_eq!(output, "00:00:00.000 info raw\0nul\n");
+}
@@ -0,0 +1,31 @@
+mod common;
+
+use common::lock;
+use rust_telemetry::{clear_output, info, set_output, stats, NullWriter};
+
+#[test]
+fn test_null_writer_discards_output() {
+    let _guard = lock();
+    set_output(NullWriter);
+    let before = stats().write_failures();
+
+    info("discarded");
+    rust_telemetry::info!("discarded {}", 2);
+
+    assert_eq!(stats().write_failures(), before);
+    clear_output();
+}
+
+#[test]
+#[cfg(not(any(
+    feature = "max_level_info",
+    feature = "max_level_warn",
+    feature = "max_level_error",
+    feature = "max_level_off"
+)))]
+fn test_static_max_level_defaults_to_debug() {
+    assert_eq!(
+        rust_telemetry::STATIC_MAX_LEVEL,
+        rust_telemetry::Level::Debug
+    );
+}
@@ -0,0 +1,28 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{
+    error, info, max_observed_level, reset_max_observed_level, track_max_observed_level, warn,
+    Level,
+};
+
+#[test]
+fn test_error_bumps_observed_level() {
+    capture(|| {
+        track_max_observed_level();
+        reset_max_observed_level();
+        assert_eq!(max_observed_level(), None);
+
+        info("all good");
+        assert_eq!(max_observed_level(), Some(Level::Info));
+        assert!(max_observed_level() < Some(Level::Error));
+
+        error("failed");
+        warn("still running");
+        assert_eq!(max_observed_level(), Some(Level::Error));
+        assert!(max_observed_level() >= Some(Level::Error));
+
+        reset_max_observed_level();
+        assert_eq!(max_observed_level(), None);
+    });
+}
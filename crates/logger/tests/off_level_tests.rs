@@ -0,0 +1,44 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{log, log_enabled, set_max_level, Level, Record};
+use std::panic;
+
+#[test]
+fn test_off_record_is_never_written() {
+    let output = capture(|| {
+        for max in [
+            Level::Debug,
+            Level::Info,
+            Level::Warn,
+            Level::Error,
+            Level::Off,
+        ] {
+            set_max_level(max);
+            // Debug builds additionally trip an assertion
+            let _ = panic::catch_unwind(|| log(&Record::new(Level::Off, "hidden")));
+            assert!(!log_enabled(Level::Off));
+        }
+        set_max_level(Level::Debug);
+    });
+
+    assert_eq!(output, "");
+}
+
+#[test]
+fn test_max_level_off_disables_output() {
+    let output = capture(|| {
+        set_max_level(Level::Off);
+        log(&Record::new(Level::Error, "dropped"));
+        set_max_level(Level::Debug);
+        log(&Record::new(Level::Error, "kept"));
+    });
+
+    assert_eq!(output, "00:00:00.000 errr kept\n");
+}
+
+#[test]
+fn test_parse_off() {
+    assert_eq!("OFF".parse::<Level>(), Ok(Level::Off));
+    assert_eq!(Level::Off.to_string(), "off");
+}
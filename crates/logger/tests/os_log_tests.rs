@@ -0,0 +1,20 @@
+#![cfg(all(feature = "apple", target_vendor = "apple"))]
+
+use rust_telemetry::{Level, OsLogPrivacy, OsLogWriter, Record, RecordWriter};
+
+#[test]
+fn test_log_type_mapping() {
+    assert_eq!(OsLogWriter::log_type(Level::Debug), 0x02);
+    assert_eq!(OsLogWriter::log_type(Level::Info), 0x01);
+    assert_eq!(OsLogWriter::log_type(Level::Warn), 0x00);
+    assert_eq!(OsLogWriter::log_type(Level::Error), 0x10);
+}
+
+#[test]
+fn test_write_record() {
+    let mut writer =
+        OsLogWriter::new("com.example.rust-telemetry", "tests").privacy(OsLogPrivacy::Public);
+    let record = Record::new(Level::Info, "hello from rust");
+
+    writer.write_record(&record, b"").unwrap();
+}
@@ -0,0 +1,164 @@
+#![cfg(feature = "otel")]
+
+use rust_telemetry::{Level, OtlpWriter, Record, RecordWriter};
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::time::{Duration, SystemTime};
+
+#[test]
+fn test_encode_maps_severity() {
+    let fields: [rust_telemetry::Field; 1] = [("user", &"ana \"a\"")];
+    let record = Record::new(Level::Warn, "disk low").with_fields(&fields);
+    let time = SystemTime::UNIX_EPOCH + Duration::from_millis(1500);
+
+    assert_eq!(
+        OtlpWriter::encode(&record, time),
+        "{\"timeUnixNano\":\"1500000000\",\"severityNumber\":13,\"severityText\":\"WARN\",\
+         \"body\":{\"stringValue\":\"disk low\"},\
+         \"attributes\":[{\"key\":\"user\",\"value\":{\"stringValue\":\"ana \\\"a\\\"\"}}]}"
+    );
+
+    let numbers: Vec<u8> = [Level::Debug, Level::Info, Level::Warn, Level::Error]
+        .into_iter()
+        .map(OtlpWriter::severity_number)
+        .collect();
+    assert_eq!(numbers, [5, 9, 13, 17]);
+}
+
+#[test]
+fn test_batch_is_posted_when_full() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let endpoint = format!("http://{}/v1/logs", listener.local_addr().unwrap());
+    let server = std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut request = Vec::new();
+        let mut buf = [0; 4096];
+        while !String::from_utf8_lossy(&request).contains("]}]}]}") {
+            let n = stream.read(&mut buf).unwrap();
+            request.extend_from_slice(&buf[..n]);
+        }
+        stream
+            .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n")
+            .unwrap();
+        String::from_utf8(request).unwrap()
+    });
+
+    let mut writer = OtlpWriter::new(&endpoint)
+        .unwrap()
+        .service_name("api")
+        .batch_size(2);
+    writer
+        .write_record(&Record::new(Level::Info, "one"), b"")
+        .unwrap();
+    writer
+        .write_record(&Record::new(Level::Error, "two"), b"")
+        .unwrap();
+
+    let request = server.join().unwrap();
+    assert!(request.starts_with("POST /v1/logs HTTP/1.1\r\n"));
+    assert!(request.contains("{\"stringValue\":\"api\"}"));
+    assert!(request.contains("\"severityNumber\":9"));
+    assert!(request.contains("\"severityNumber\":17"));
+}
+
+#[test]
+fn test_rejects_non_http_endpoint() {
+    assert!(OtlpWriter::new("https://collector:4318").is_err());
+}
+
+/// Read one HTTP request from `stream`, up to the end of the JSON payload
+fn read_request(stream: &mut std::net::TcpStream) -> String {
+    let mut request = Vec::new();
+    let mut buf = [0; 4096];
+    while !String::from_utf8_lossy(&request).contains("]}]}]}") {
+        let n = stream.read(&mut buf).unwrap();
+        request.extend_from_slice(&buf[..n]);
+    }
+    String::from_utf8(request).unwrap()
+}
+
+#[test]
+fn test_hanging_collector_does_not_block_writes() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let endpoint = format!("http://{}/v1/logs", listener.local_addr().unwrap());
+    // Accept and read, but never answer
+    let server = std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        read_request(&mut stream);
+        std::thread::sleep(Duration::from_millis(500));
+    });
+
+    let mut writer = OtlpWriter::new(&endpoint)
+        .unwrap()
+        .batch_size(1)
+        .timeout(Duration::from_millis(100));
+    let started = std::time::Instant::now();
+    for _ in 0..10 {
+        writer
+            .write_record(&Record::new(Level::Info, "stuck"), b"")
+            .unwrap();
+    }
+    assert!(started.elapsed() < Duration::from_millis(100));
+
+    assert!(!RecordWriter::shutdown(
+        &mut writer,
+        Duration::from_millis(50)
+    ));
+    server.join().unwrap();
+}
+
+#[test]
+fn test_failed_batch_is_retried_and_shutdown_sends_pending() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let endpoint = format!("http://{}/v1/logs", listener.local_addr().unwrap());
+    let server = std::thread::spawn(move || {
+        let mut requests = Vec::new();
+        for status in ["503 Service Unavailable", "200 OK"] {
+            let (mut stream, _) = listener.accept().unwrap();
+            requests.push(read_request(&mut stream));
+            let response = format!("HTTP/1.1 {status}\r\nContent-Length: 0\r\n\r\n");
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+        requests
+    });
+
+    let mut writer = OtlpWriter::new(&endpoint)
+        .unwrap()
+        .batch_size(2)
+        .batch_timeout(Duration::from_secs(60));
+    for message in ["one", "two", "three"] {
+        writer
+            .write_record(&Record::new(Level::Info, message), b"")
+            .unwrap();
+    }
+    assert!(RecordWriter::shutdown(&mut writer, Duration::from_secs(5)));
+
+    let requests = server.join().unwrap();
+    assert!(requests[0].contains("\"one\"") && requests[0].contains("\"two\""));
+    for message in ["\"one\"", "\"two\"", "\"three\""] {
+        assert!(requests[1].contains(message), "{message} not retried");
+    }
+}
+
+#[test]
+fn test_record_time_is_exported() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let endpoint = format!("http://{}/v1/logs", listener.local_addr().unwrap());
+    let server = std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let request = read_request(&mut stream);
+        stream
+            .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n")
+            .unwrap();
+        request
+    });
+
+    let mut writer = OtlpWriter::new(&endpoint).unwrap().batch_size(1);
+    let record = Record::new(Level::Info, "released").with_time(Duration::from_millis(1500));
+    writer.write_record(&record, b"").unwrap();
+
+    assert!(server
+        .join()
+        .unwrap()
+        .contains("\"timeUnixNano\":\"1500000000\""));
+}
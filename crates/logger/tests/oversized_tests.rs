@@ -0,0 +1,105 @@
+mod common;
+
+use common::lock;
+use rust_telemetry::{
+    clear_timestamp, set_oversized_policy, set_timestamp, Field, Level, OversizedPolicy, Record,
+    RecordWriter, UdpWriter,
+};
+use std::net::UdpSocket;
+use std::time::Duration;
+
+/// Collect the datagrams that arrive at `receiver`
+fn receive(receiver: &UdpSocket) -> Vec<String> {
+    let mut datagrams = Vec::new();
+    let mut buf = [0u8; 256];
+    while let Ok(len) = receiver.recv(&mut buf) {
+        datagrams.push(String::from_utf8_lossy(&buf[..len]).to_string());
+    }
+    datagrams
+}
+
+/// Send `record` (rendered as `line`) through a UDP writer limited to `max` bytes
+fn send(policy: OversizedPolicy, max: usize, record: &Record, line: &str) -> Vec<String> {
+    let _guard = lock();
+    let receiver = UdpSocket::bind("127.0.0.1:0").unwrap();
+    receiver
+        .set_read_timeout(Some(Duration::from_millis(200)))
+        .unwrap();
+    let mut writer = UdpWriter::new(receiver.local_addr().unwrap())
+        .unwrap()
+        .max_datagram(max);
+
+    set_oversized_policy(policy);
+    set_timestamp("12:00:00.000");
+    writer.write_record(record, line.as_bytes()).unwrap();
+    clear_timestamp();
+    set_oversized_policy(OversizedPolicy::Truncate);
+    receive(&receiver)
+}
+
+/// Send one oversized line through a UDP writer and collect the datagrams
+fn send_oversized(policy: OversizedPolicy) -> Vec<String> {
+    let message = "abcdefghijklmnopqrstuvwxyz0123456789";
+    let fields: [Field; 1] = [("id", &7)];
+    let record = Record::new(Level::Warn, message).with_fields(&fields);
+    let line = format!("12:00:00.000 warn {message} id=7\n");
+    send(policy, 40, &record, &line)
+}
+
+#[test]
+fn test_truncate_adds_marker() {
+    assert_eq!(
+        send_oversized(OversizedPolicy::Truncate),
+        ["12:00:00.000 warn ab...[truncated] id=7\n"]
+    );
+}
+
+#[test]
+fn test_split_repeats_header() {
+    let datagrams = send_oversized(OversizedPolicy::Split);
+
+    assert_eq!(
+        datagrams,
+        [
+            "12:00:00.000 warn abcdefghijklmnop id=7\n",
+            "12:00:00.000 warn qrstuvwxyz012345 id=7\n",
+            "12:00:00.000 warn 6789 id=7\n",
+        ]
+    );
+    assert!(datagrams.iter().all(|d| d.len() <= 40));
+}
+
+#[test]
+fn test_drop_sends_nothing() {
+    assert!(send_oversized(OversizedPolicy::Drop).is_empty());
+}
+
+#[test]
+fn test_message_matching_the_level_label_keeps_the_header() {
+    // "warn" also appears as the level label
+    let fields: [Field; 1] = [("detail", &"x".repeat(30))];
+    let record = Record::new(Level::Warn, "warn").with_fields(&fields);
+    let line = format!("12:00:00.000 warn warn detail={}\n", "x".repeat(30));
+
+    assert_eq!(
+        send(OversizedPolicy::Truncate, 50, &record, &line),
+        ["12:00:00.000 warn warn detail=xxxxxx...[truncated]"]
+    );
+}
+
+#[test]
+fn test_oversized_fields_truncate_the_whole_line() {
+    let fields: [Field; 1] = [("payload", &"y".repeat(80))];
+    let record = Record::new(Level::Info, "1").with_fields(&fields);
+    let line = format!("12:00:00.000 info 1 payload={}\n", "y".repeat(80));
+
+    let datagrams = send(OversizedPolicy::Truncate, 60, &record, &line);
+    assert_eq!(
+        datagrams,
+        [format!(
+            "12:00:00.000 info 1 payload={}...[truncated]",
+            "y".repeat(18)
+        )]
+    );
+    assert!(datagrams[0].len() <= 60);
+}
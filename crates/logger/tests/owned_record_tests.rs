@@ -0,0 +1,32 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{Field, Level, OwnedRecord, Record};
+
+#[test]
+fn test_owned_record_logged_after_source_dropped() {
+    let mut stash = Vec::new();
+    {
+        let message = format!("batch item {}", 7);
+        let id = String::from("abc");
+        let fields: [Field; 1] = [("id", &id)];
+        let record = Record::new(Level::Warn, &message)
+            .with_target("jobs")
+            .with_fields(&fields);
+        stash.push(OwnedRecord::from(&record));
+    }
+    stash.push(OwnedRecord::new(Level::Info, "built directly").with_field("n", 3));
+
+    let output = capture(|| {
+        for record in &stash {
+            record.log();
+        }
+    });
+
+    assert_eq!(
+        output,
+        "00:00:00.000 warn batch item 7 id=abc\n00:00:00.000 info built directly n=3\n"
+    );
+    let target = stash[0].with_record(|r| r.target().map(str::to_string));
+    assert_eq!(target.as_deref(), Some("jobs"));
+}
@@ -0,0 +1,62 @@
+mod common;
+
+use common::{capture, compiled_in};
+use rust_telemetry::{info, log, Level, Record, RecordWriter};
+use std::fmt;
+use std::io;
+
+struct Explodes;
+
+impl fmt::Display for Explodes {
+    fn fmt(&self, _: &mut fmt::Formatter) -> fmt::Result {
+        panic!("bad display")
+    }
+}
+
+struct PanicWriter;
+
+impl RecordWriter for PanicWriter {
+    fn write_record(&mut self, _: &Record, _: &[u8]) -> io::Result<()> {
+        panic!("bad writer")
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_panicking_display_yields_placeholder() {
+    if !compiled_in(Level::Info) {
+        return;
+    }
+    let output = capture(|| {
+        rust_telemetry::info!("value is {}", Explodes);
+        let fields: [rust_telemetry::Field; 1] = [("bad", &Explodes)];
+        log(&Record::new(Level::Warn, "with field").with_fields(&fields));
+        info("still working");
+    });
+
+    assert_eq!(
+        output,
+        "00:00:00.000 info <panic while formatting>\n\
+         00:00:00.000 warn with field bad=<panic while formatting>\n\
+         00:00:00.000 info still working\n"
+    );
+}
+
+#[test]
+fn test_poisoned_writer_is_recovered() {
+    let output = capture(|| {
+        let buffer = rust_telemetry::MemoryWriter::new();
+        rust_telemetry::set_output(PanicWriter);
+        let result = std::thread::spawn(|| info("poisons the writer")).join();
+        assert!(result.is_err());
+
+        rust_telemetry::set_output(buffer.writer());
+        info("after panic");
+        assert_eq!(buffer.contents(), "00:00:00.000 info after panic\n");
+    });
+
+    assert_eq!(output, "");
+}
@@ -0,0 +1,40 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{info, set_output, MemoryWriter, Record, RecordWriter};
+use std::io;
+use std::thread;
+
+/// Writer that panics while the global writer lock is held
+struct PanicWriter;
+
+impl RecordWriter for PanicWriter {
+    fn write_record(&mut self, _: &Record, _: &[u8]) -> io::Result<()> {
+        panic!("writer failed")
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_logging_continues_on_another_thread_after_poison() {
+    let output = capture(|| {
+        set_output(PanicWriter);
+        assert!(thread::spawn(|| info("poisons")).join().is_err());
+
+        let buffer = MemoryWriter::new();
+        let writer = buffer.writer();
+        thread::spawn(move || {
+            set_output(writer);
+            info("from another thread");
+        })
+        .join()
+        .unwrap();
+
+        assert_eq!(buffer.contents(), "00:00:00.000 info from another thread\n");
+    });
+
+    assert_eq!(output, "");
+}
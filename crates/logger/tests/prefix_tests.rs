@@ -0,0 +1,49 @@
+mod common;
+
+use common::lock;
+use rust_telemetry::{
+    clear_output, clear_timestamp, error, info, log, set_format, set_output, set_timestamp, Field,
+    Format, Level, MemoryWriter, PrefixWriter, Record,
+};
+
+#[test]
+fn test_prefix_on_every_line_once() {
+    let _guard = lock();
+    let buffer = MemoryWriter::new();
+    set_output(PrefixWriter::new(buffer.writer(), "[auth] "));
+    set_timestamp("00:00:00.000");
+
+    info("login");
+    error("bad password");
+
+    clear_timestamp();
+    clear_output();
+    assert_eq!(
+        buffer.contents(),
+        "[auth] 00:00:00.000 info login\n[auth] 00:00:00.000 errr bad password\n"
+    );
+}
+
+#[test]
+fn test_prefix_on_continuation_lines() {
+    let _guard = lock();
+    let fields: [Field; 1] = [("table", &"users")];
+    let buffer = MemoryWriter::new();
+    set_output(PrefixWriter::new(buffer.writer(), "[db] "));
+    set_timestamp("00:00:00.000");
+    set_format(Format::Pretty);
+
+    log(&Record::new(Level::Info, "query").with_fields(&fields));
+
+    set_format(Format::Text);
+    clear_timestamp();
+    clear_output();
+    let contents = buffer.contents();
+    assert_eq!(
+        contents,
+        "[db] 00:00:00.000 info query\n[db]     table: users\n"
+    );
+    assert!(contents
+        .lines()
+        .all(|line| line.matches("[db]").count() == 1));
+}
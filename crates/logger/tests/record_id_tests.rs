@@ -0,0 +1,39 @@
+mod common;
+
+use common::{capture, compiled_in};
+use rust_telemetry::{info, set_show_record_id, Level, RECORD_ID_LEN};
+use std::collections::HashSet;
+
+#[test]
+fn test_ids_are_distinct_and_fixed_length() {
+    if !compiled_in(Level::Info) {
+        return;
+    }
+    let output = capture(|| {
+        set_show_record_id(true);
+        for _ in 0..1000 {
+            info!("tick");
+        }
+        set_show_record_id(false);
+    });
+
+    let ids: Vec<&str> = output
+        .lines()
+        .map(|line| line.split_once("record_id=").unwrap().1)
+        .collect();
+    assert_eq!(ids.len(), 1000);
+    assert!(ids
+        .iter()
+        .all(|id| id.len() == RECORD_ID_LEN && id.chars().all(|c| c.is_ascii_alphanumeric())));
+    assert_eq!(ids.iter().collect::<HashSet<_>>().len(), ids.len());
+}
+
+#[test]
+fn test_no_id_by_default() {
+    if !compiled_in(Level::Info) {
+        return;
+    }
+    let output = capture(|| info!("plain"));
+
+    assert_eq!(output, "00:00:00.000 info plain\n");
+}
@@ -0,0 +1,49 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{log, Field, Level, Record};
+
+#[test]
+fn test_mutable_setters_build_incrementally() {
+    let fields: [Field; 1] = [("attempt", &3)];
+    let output = capture(|| {
+        let mut record = Record::new(Level::Info, "retrying");
+        for (index, line) in [10, 20, 30].into_iter().enumerate() {
+            if index > 0 {
+                record.set_line(line);
+            }
+        }
+        record
+            .set_level(Level::Warn)
+            .set_target("app::net")
+            .set_file("src/net.rs")
+            .set_fields(&fields);
+        log(&record);
+    });
+
+    assert_eq!(output, "00:00:00.000 warn retrying attempt=3\n");
+}
+
+#[test]
+fn test_setters_match_consuming_builders() {
+    let fields: [Field; 1] = [("k", &"v")];
+    let built = Record::new(Level::Error, "boom")
+        .with_target("app")
+        .with_location("src/main.rs", 7)
+        .with_fields(&fields);
+
+    let mut set = Record::new(Level::Debug, "");
+    set.set_level(Level::Error)
+        .set_message("boom")
+        .set_target("app")
+        .set_file("src/main.rs")
+        .set_line(7)
+        .set_fields(&fields);
+
+    assert_eq!(set.level(), built.level());
+    assert_eq!(set.message(), built.message());
+    assert_eq!(set.target(), built.target());
+    assert_eq!(set.file(), built.file());
+    assert_eq!(set.line(), built.line());
+    assert_eq!(set.fields().len(), built.fields().len());
+}
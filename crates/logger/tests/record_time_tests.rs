@@ -0,0 +1,40 @@
+mod common;
+
+use common::lock;
+use rust_telemetry::{
+    clear_clock, clear_output, info, set_fixed_time, set_format, set_formatter, set_output, Format,
+    Formatter, MemoryWriter, Record,
+};
+use std::fmt::Write;
+use std::time::{Duration, SystemTime};
+
+/// Formatter rendering the raw time as epoch seconds
+struct EpochFormatter;
+
+impl Formatter for EpochFormatter {
+    fn format(&self, record: &Record, _time: &str, out: &mut String) {
+        let secs = record.time().map_or(0, |time| time.as_secs());
+        let _ = write!(out, "{secs} {} {}", record.level(), record.message());
+    }
+}
+
+#[test]
+fn test_formatter_renders_epoch_seconds() {
+    let _guard = lock();
+    let buffer = MemoryWriter::new();
+    set_output(buffer.writer());
+    set_fixed_time(SystemTime::UNIX_EPOCH + Duration::from_millis(1_700_000_000_250));
+    set_formatter(Box::new(EpochFormatter));
+
+    info("tick");
+
+    set_format(Format::Text);
+    clear_clock();
+    clear_output();
+    assert_eq!(buffer.contents(), "1700000000 info tick\n");
+}
+
+#[test]
+fn test_time_unset_outside_logger() {
+    assert_eq!(Record::new(rust_telemetry::Level::Info, "x").time(), None);
+}
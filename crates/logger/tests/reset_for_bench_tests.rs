@@ -0,0 +1,67 @@
+mod common;
+
+use common::lock;
+use rust_telemetry::{
+    clear_output, effective_config, info, log_kv, reset_for_bench, set_compact_timestamps,
+    set_dedup_location, set_field_layout, set_field_separator, set_format, set_kv_separator,
+    set_level_names, set_line_terminator, set_max_fields, set_max_level, set_module_level,
+    set_output, set_sanitize_messages, set_show_record_id, set_skip_empty, set_text_field_style,
+    set_timestamp, set_timestamp_position, set_trim_message, stats, Field, Format, LayoutField,
+    Level, MemoryWriter, TextFieldStyle, TimestampPosition,
+};
+
+#[test]
+fn test_reset_restores_defaults_with_null_output() {
+    let _guard = lock();
+    set_max_level(Level::Error);
+    set_module_level("noisy", Level::Off);
+    set_format(Format::Pretty);
+    set_timestamp("00:00:00.000");
+
+    reset_for_bench();
+    info("discarded");
+
+    let config = effective_config();
+    assert_eq!(config.max_level, Level::Debug);
+    assert!(config.module_levels.is_empty());
+    assert_eq!(config.format, "text");
+    assert_eq!(config.fixed_timestamp, None);
+    assert!(config.writer.ends_with("NullWriter"), "{}", config.writer);
+    assert_eq!(stats().total_emitted(), 1);
+
+    clear_output();
+}
+
+#[test]
+fn test_reset_restores_output_settings() {
+    let _guard = lock();
+    set_sanitize_messages(true);
+    set_trim_message(false);
+    set_skip_empty(true);
+    set_max_fields(0);
+    set_dedup_location(true);
+    set_timestamp_position(TimestampPosition::Suffix);
+    set_compact_timestamps(true);
+    set_line_terminator("\r\n");
+    set_level_names(&[(Level::Info, "INFORMATION")]);
+    set_field_layout(&[LayoutField::Message, LayoutField::Level]);
+    set_field_separator(" | ");
+    set_kv_separator(": ");
+    set_text_field_style(TextFieldStyle::Json);
+    set_show_record_id(true);
+
+    reset_for_bench();
+    let buffer = MemoryWriter::new();
+    set_output(buffer.writer());
+    set_timestamp("00:00:00.000");
+    let fields: [Field; 1] = [("k", &"v")];
+    log_kv(Level::Info, "msg\t ", &fields);
+    log_kv(Level::Info, "msg\t ", &fields);
+    reset_for_bench();
+
+    assert_eq!(
+        buffer.contents(),
+        "00:00:00.000 info msg k=v\n00:00:00.000 info msg k=v\n"
+    );
+    clear_output();
+}
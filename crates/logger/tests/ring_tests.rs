@@ -0,0 +1,65 @@
+mod common;
+
+use common::lock;
+use rust_telemetry::{
+    clear_output, clear_timestamp, error, info, set_format, set_output, set_show_seq,
+    set_timestamp, set_timestamp_format, set_timestamp_position, warn, Format, Level,
+    RingBufferWriter, TimestampFormat, TimestampPosition,
+};
+
+#[test]
+fn test_ring_buffer_wraps_and_snapshots_newest_first() {
+    let _guard = lock();
+    let ring = RingBufferWriter::new(3);
+    set_output(ring.writer());
+    set_timestamp("00:00:00.000");
+
+    info("one");
+    info("two");
+    warn("three");
+    error("four with spaces");
+
+    clear_timestamp();
+    clear_output();
+
+    let snapshot = ring.snapshot();
+    assert_eq!(snapshot.len(), 3);
+    assert_eq!(snapshot[0].level, Level::Error);
+    assert_eq!(snapshot[0].time, "00:00:00.000");
+    assert_eq!(snapshot[0].message, "four with spaces");
+    assert_eq!(snapshot[1].message, "three");
+    assert_eq!(snapshot[2].message, "two");
+}
+
+#[test]
+fn test_entries_do_not_depend_on_the_line_layout() {
+    let _guard = lock();
+    let ring = RingBufferWriter::new(4);
+    set_output(ring.writer());
+    set_timestamp_format(TimestampFormat::Rfc3339);
+    set_timestamp_position(TimestampPosition::Suffix);
+    set_show_seq(true);
+
+    for format in [Format::Pretty, Format::Gutter, Format::Text] {
+        set_format(format);
+        warn("disk low: 3% left");
+    }
+    set_timestamp_position(TimestampPosition::None);
+    info("info untimed");
+
+    set_format(Format::Text);
+    set_show_seq(false);
+    set_timestamp_position(TimestampPosition::Prefix);
+    set_timestamp_format(TimestampFormat::TimeOfDay);
+    clear_output();
+
+    let snapshot = ring.snapshot();
+    assert_eq!(snapshot.len(), 4);
+    assert_eq!(snapshot[0].level, Level::Info);
+    assert_eq!(snapshot[0].message, "info untimed");
+    for entry in &snapshot[1..] {
+        assert_eq!(entry.level, Level::Warn);
+        assert_eq!(entry.message, "disk low: 3% left");
+        assert!(entry.time.ends_with('Z'), "{:?}", entry.time);
+    }
+}
@@ -0,0 +1,196 @@
+use rust_telemetry::{RollInterval, RollingFileWriter};
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+/// Fresh directory under the system temp dir
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("rust-telemetry-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Sorted file names in `dir`
+fn files(dir: &PathBuf) -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(dir)
+        .unwrap()
+        .map(|e| e.unwrap().file_name().to_string_lossy().to_string())
+        .collect();
+    names.sort();
+    names
+}
+
+/// Clock closure reading seconds from a shared counter
+fn mock_clock(secs: &Arc<AtomicU64>) -> impl Fn() -> SystemTime + Send + 'static {
+    let secs = Arc::clone(secs);
+    move || SystemTime::UNIX_EPOCH + Duration::from_secs(secs.load(Ordering::Relaxed))
+}
+
+#[test]
+fn test_size_rolls_before_time() {
+    let dir = temp_dir("size-first");
+    let secs = Arc::new(AtomicU64::new(7200));
+    let mut writer = RollingFileWriter::new(dir.join("app.log"))
+        .max_size(10)
+        .interval(RollInterval::Hourly)
+        .with_clock(mock_clock(&secs));
+
+    // Burst of size rolls within one hour
+    writer.write_all(b"line 1\n").unwrap();
+    writer.write_all(b"line 2\n").unwrap();
+    writer.write_all(b"line 3\n").unwrap();
+    writer.flush().unwrap();
+
+    assert_eq!(files(&dir), ["app.log", "app.log.7200.0", "app.log.7200.1"]);
+    assert_eq!(
+        fs::read_to_string(dir.join("app.log.7200.0")).unwrap(),
+        "line 1\n"
+    );
+    assert_eq!(fs::read_to_string(dir.join("app.log")).unwrap(), "line 3\n");
+
+    // Next hour resets the sequence
+    secs.store(10800, Ordering::Relaxed);
+    writer.write_all(b"line 4\n").unwrap();
+    writer.flush().unwrap();
+    assert_eq!(
+        files(&dir),
+        [
+            "app.log",
+            "app.log.7200.0",
+            "app.log.7200.1",
+            "app.log.7200.2"
+        ]
+    );
+    assert_eq!(fs::read_to_string(dir.join("app.log")).unwrap(), "line 4\n");
+}
+
+#[test]
+fn test_time_rolls_before_size() {
+    let dir = temp_dir("time-first");
+    let secs = Arc::new(AtomicU64::new(3600));
+    let mut writer = RollingFileWriter::new(dir.join("app.log"))
+        .max_size(1024)
+        .interval(RollInterval::Hourly)
+        .with_clock(mock_clock(&secs));
+
+    writer.write_all(b"before idle\n").unwrap();
+
+    // Idle across several windows rolls once and creates no empty files
+    secs.store(3600 * 5 + 30, Ordering::Relaxed);
+    writer.write_all(b"after idle\n").unwrap();
+    writer.flush().unwrap();
+
+    assert_eq!(files(&dir), ["app.log", "app.log.3600.0"]);
+    assert_eq!(
+        fs::read_to_string(dir.join("app.log.3600.0")).unwrap(),
+        "before idle\n"
+    );
+    assert_eq!(
+        fs::read_to_string(dir.join("app.log")).unwrap(),
+        "after idle\n"
+    );
+}
+
+#[test]
+fn test_max_files_prunes_oldest() {
+    let dir = temp_dir("retention");
+    let mut writer = RollingFileWriter::new(dir.join("app.log"))
+        .max_size(4)
+        .max_files(2);
+
+    for line in [b"aaa\n", b"bbb\n", b"ccc\n", b"ddd\n"] {
+        writer.write_all(line).unwrap();
+    }
+    writer.flush().unwrap();
+
+    assert_eq!(files(&dir), ["app.log", "app.log.0.1", "app.log.0.2"]);
+    assert_eq!(fs::read_to_string(dir.join("app.log")).unwrap(), "ddd\n");
+}
+
+#[test]
+fn test_reopening_keeps_and_prunes_files_from_earlier_runs() {
+    let dir = temp_dir("reopen");
+    let run = |lines: &[&[u8]]| {
+        let mut writer = RollingFileWriter::new(dir.join("app.log"))
+            .max_size(4)
+            .max_files(3);
+        for line in lines {
+            writer.write_all(line).unwrap();
+            writer.flush().unwrap();
+            // Distinct modification times keep the age order unambiguous
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    };
+
+    run(&[b"aaa\n", b"bbb\n", b"ccc\n"]);
+    assert_eq!(files(&dir), ["app.log", "app.log.0.0", "app.log.0.1"]);
+
+    // A new run continues the sequence instead of overwriting `app.log.0.0`
+    run(&[b"ddd\n"]);
+    assert_eq!(
+        files(&dir),
+        ["app.log", "app.log.0.0", "app.log.0.1", "app.log.0.2"]
+    );
+    assert_eq!(
+        fs::read_to_string(dir.join("app.log.0.0")).unwrap(),
+        "aaa\n"
+    );
+    assert_eq!(
+        fs::read_to_string(dir.join("app.log.0.2")).unwrap(),
+        "ccc\n"
+    );
+
+    // Retention counts the earlier run's files and deletes the oldest first
+    run(&[b"eee\n"]);
+    assert_eq!(
+        files(&dir),
+        ["app.log", "app.log.0.1", "app.log.0.2", "app.log.0.3"]
+    );
+    assert_eq!(fs::read_to_string(dir.join("app.log")).unwrap(), "eee\n");
+}
+
+#[test]
+fn test_retention_spares_files_outside_the_naming_scheme() {
+    let dir = temp_dir("unrelated");
+    fs::write(dir.join("app.log.bak"), "backup").unwrap();
+    fs::write(dir.join("app.log.lock"), "").unwrap();
+    let mut writer = RollingFileWriter::new(dir.join("app.log"))
+        .max_size(4)
+        .max_files(1);
+    for line in [b"aaa\n", b"bbb\n", b"ccc\n"] {
+        writer.write_all(line).unwrap();
+    }
+    writer.flush().unwrap();
+
+    assert_eq!(
+        files(&dir),
+        ["app.log", "app.log.0.1", "app.log.bak", "app.log.lock"]
+    );
+}
+
+#[test]
+fn test_retention_spares_files_sharing_a_custom_prefix() {
+    fn naming(path: &std::path::Path, window: u64, seq: u32) -> PathBuf {
+        path.with_file_name(format!("app-{window}-{seq}.log"))
+    }
+    let dir = temp_dir("unrelated-custom");
+    fs::write(dir.join("app-config.yaml"), "port: 80").unwrap();
+    fs::write(dir.join("app-old.log"), "").unwrap();
+    let mut writer = RollingFileWriter::new(dir.join("app.log"))
+        .max_size(4)
+        .max_files(1)
+        .naming(naming);
+    for line in [b"aaa\n", b"bbb\n", b"ccc\n"] {
+        writer.write_all(line).unwrap();
+    }
+    writer.flush().unwrap();
+
+    assert_eq!(
+        files(&dir),
+        ["app-0-1.log", "app-config.yaml", "app-old.log", "app.log"]
+    );
+}
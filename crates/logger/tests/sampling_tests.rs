@@ -0,0 +1,56 @@
+mod common;
+
+use common::{capture, compiled_in};
+use rust_telemetry::{debug, error, set_sampling_rules, stats, Level};
+
+#[test]
+fn test_targets_sample_at_their_own_rates() {
+    if !compiled_in(Level::Debug) {
+        return;
+    }
+    let mut dropped = 0;
+    let output = capture(|| {
+        stats().reset();
+        set_sampling_rules(vec![
+            ("payments::*".to_string(), Level::Debug, 1.0),
+            ("render".to_string(), Level::Debug, 0.01),
+            ("render::gpu".to_string(), Level::Debug, 0.5),
+        ]);
+        for _ in 0..1000 {
+            debug!(target: "payments::card", "charge");
+            debug!(target: "render::ui", "frame");
+            debug!(target: "render::gpu::shader", "compile");
+        }
+        dropped = stats().suppressed_by_sampling();
+        set_sampling_rules(Vec::new());
+    });
+
+    assert_eq!(output.matches("charge").count(), 1000);
+    assert_eq!(output.matches("frame").count(), 10);
+    assert_eq!(output.matches("compile").count(), 500);
+    assert_eq!(dropped, 990 + 500);
+}
+
+#[test]
+fn test_records_above_rule_level_are_kept() {
+    if !compiled_in(Level::Debug) {
+        return;
+    }
+    let mut dropped = 0;
+    let output = capture(|| {
+        stats().reset();
+        set_sampling_rules(vec![("noisy".to_string(), Level::Info, 0.0)]);
+        for _ in 0..5 {
+            debug!(target: "noisy", "chatter");
+            error!(target: "noisy", "failure");
+        }
+        debug!(target: "quiet", "unaffected");
+        dropped = stats().suppressed_by_sampling();
+        set_sampling_rules(Vec::new());
+    });
+
+    assert_eq!(output.matches("chatter").count(), 0);
+    assert_eq!(output.matches("failure").count(), 5);
+    assert_eq!(output.matches("unaffected").count(), 1);
+    assert_eq!(dropped, 5);
+}
@@ -0,0 +1,47 @@
+mod common;
+
+use common::{capture, capture_raw, compiled_in};
+use rust_telemetry::{info, set_escape_newlines, set_sanitize_messages, warn, Level};
+
+#[test]
+fn test_sanitize_neutralizes_escapes_and_newlines() {
+    let output = capture_raw(|| {
+        set_sanitize_messages(true);
+        warn("user input: \x1b[2Jcleared\nfake line");
+        set_sanitize_messages(false);
+    });
+
+    assert_eq!(
+        output,
+        "00:00:00.000 \x1b[33mwarn\x1b[0m user input: \\x1b[2Jcleared\\nfake line\n"
+    );
+    assert_eq!(output.matches('\x1b').count(), 2);
+    assert_eq!(output.lines().count(), 1);
+}
+
+#[test]
+fn test_newlines_escaped_by_default() {
+    if !compiled_in(Level::Info) {
+        return;
+    }
+    let output = capture(|| {
+        info("line1\nline2\r\nline3");
+        rust_telemetry::info!("user said: {}", "hi\nthere");
+    });
+
+    assert_eq!(
+        output,
+        "00:00:00.000 info line1\\nline2\\r\\nline3\n00:00:00.000 info user said: hi\\nthere\n"
+    );
+}
+
+#[test]
+fn test_multiline_opt_out() {
+    let output = capture(|| {
+        set_escape_newlines(false);
+        info("line1\nline2");
+        set_escape_newlines(true);
+    });
+
+    assert_eq!(output, "00:00:00.000 info line1\nline2\n");
+}
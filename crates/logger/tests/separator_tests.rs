@@ -0,0 +1,27 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{log_kv, set_field_separator, set_kv_separator, Field, Level};
+
+/// Log a record with two fields
+fn log_sample() {
+    let fields: [Field; 2] = [("user", &"ana"), ("id", &7)];
+    log_kv(Level::Info, "login", &fields);
+}
+
+#[test]
+fn test_custom_separators() {
+    let output = capture(|| {
+        set_field_separator(" | ");
+        set_kv_separator(": ");
+        log_sample();
+        set_field_separator(" ");
+        set_kv_separator("=");
+        log_sample();
+    });
+
+    assert_eq!(
+        output,
+        "00:00:00.000 info login | user: ana id: 7\n00:00:00.000 info login user=ana id=7\n"
+    );
+}
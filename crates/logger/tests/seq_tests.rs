@@ -0,0 +1,39 @@
+mod common;
+
+use common::{capture, compiled_in};
+use rust_telemetry::{info, set_show_seq, warn, Level};
+
+/// Parse the `#N` sequence number from each line
+fn seqs(output: &str) -> Vec<u64> {
+    output
+        .lines()
+        .map(|line| {
+            let token = line.split(' ').nth(1).unwrap();
+            token.strip_prefix('#').unwrap().parse().unwrap()
+        })
+        .collect()
+}
+
+#[test]
+fn test_sequence_numbers_strictly_increase() {
+    if !compiled_in(Level::Info) {
+        return;
+    }
+    let output = capture(|| {
+        set_show_seq(true);
+        info("first");
+        rust_telemetry::info!("second");
+        warn("third");
+        set_show_seq(false);
+    });
+
+    let seqs = seqs(&output);
+    assert_eq!(seqs.len(), 3);
+    assert!(seqs[0] < seqs[1] && seqs[1] < seqs[2], "{output}");
+}
+
+#[test]
+fn test_sequence_hidden_by_default() {
+    let output = capture(|| info("plain"));
+    assert_eq!(output, "00:00:00.000 info plain\n");
+}
@@ -0,0 +1,62 @@
+mod common;
+
+use common::{compiled_in, lock};
+use rust_telemetry::{clear_output, error, info, set_output, Level, LevelShardedFileWriter};
+use std::fs;
+use std::path::PathBuf;
+
+/// Fresh, not yet created directory under the system temp dir
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("rust-telemetry-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn test_levels_land_in_separate_files() {
+    if !compiled_in(Level::Info) {
+        return;
+    }
+    let _guard = lock();
+    let dir = temp_dir("sharded");
+    set_output(LevelShardedFileWriter::new(&dir));
+
+    rust_telemetry::info!("server started");
+    rust_telemetry::error!("disk full");
+    info("request served");
+
+    clear_output();
+    let info_log = fs::read_to_string(dir.join("info.log")).unwrap();
+    let error_log = fs::read_to_string(dir.join("error.log")).unwrap();
+    assert!(info_log.contains("info server started\n"), "{info_log:?}");
+    assert!(info_log.contains("info request served\n"), "{info_log:?}");
+    assert!(!info_log.contains("disk full"));
+    assert!(error_log.ends_with("errr disk full\n"), "{error_log:?}");
+    assert!(!dir.join("debug.log").exists());
+    assert!(!dir.join("warn.log").exists());
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_rotation_applies_per_shard() {
+    let _guard = lock();
+    let dir = temp_dir("sharded-roll");
+    set_output(LevelShardedFileWriter::new(&dir).max_size(30).max_files(1));
+
+    for i in 0..3 {
+        error(&format!("failure {i}"));
+    }
+    info("quiet");
+
+    clear_output();
+    let mut names: Vec<String> = fs::read_dir(&dir)
+        .unwrap()
+        .map(|e| e.unwrap().file_name().to_string_lossy().to_string())
+        .collect();
+    names.sort();
+    assert_eq!(names.len(), 3, "{names:?}");
+    assert_eq!(names[0], "error.log");
+    assert!(names[1].starts_with("error.log."));
+    assert_eq!(names[2], "info.log");
+    fs::remove_dir_all(&dir).unwrap();
+}
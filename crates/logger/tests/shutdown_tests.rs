@@ -0,0 +1,94 @@
+mod common;
+
+use common::{compiled_in, lock};
+use rust_telemetry::{
+    clear_output, clear_timestamp, info, log_to, set_color_enabled, set_output, set_timestamp,
+    shutdown, Level, MemoryWriter, NonBlockingWriter, OverflowPolicy, Record,
+};
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+/// Writer that takes a while for every write
+#[derive(Clone)]
+struct SlowWriter(MemoryWriter);
+
+impl Write for SlowWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        thread::sleep(Duration::from_millis(20));
+        self.0.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_shutdown_drains_queued_records() {
+    if !compiled_in(Level::Info) {
+        return;
+    }
+    let _guard = lock();
+    let output = MemoryWriter::new();
+    let writer = NonBlockingWriter::new(SlowWriter(output.clone()), 64)
+        .overflow_policy(OverflowPolicy::Block);
+    set_color_enabled(false);
+    set_timestamp("00:00:00.000");
+    set_output(writer);
+
+    for i in 0..10 {
+        info!("record {i}");
+    }
+    let drained = shutdown(Duration::from_secs(5));
+    let contents = output.contents();
+    clear_output();
+    clear_timestamp();
+    set_color_enabled(true);
+
+    assert!(drained);
+    for i in 0..10 {
+        assert!(
+            contents.contains(&format!("info record {i}\n")),
+            "{contents}"
+        );
+    }
+}
+
+#[test]
+fn test_logging_after_shutdown_writes_directly() {
+    if !compiled_in(Level::Info) {
+        return;
+    }
+    let _guard = lock();
+    let output = MemoryWriter::new();
+    let writer = NonBlockingWriter::new(output.clone(), 16);
+    set_color_enabled(false);
+    set_timestamp("00:00:00.000");
+    set_output(writer);
+
+    info!("before");
+    assert!(shutdown(Duration::from_secs(5)));
+    info!("after");
+    let contents = output.contents();
+    clear_output();
+    clear_timestamp();
+    set_color_enabled(true);
+
+    assert_eq!(
+        contents,
+        "00:00:00.000 info before\n00:00:00.000 info after\n"
+    );
+}
+
+#[test]
+fn test_shutdown_times_out() {
+    let output = MemoryWriter::new();
+    let mut writer = NonBlockingWriter::new(SlowWriter(output.clone()), 64)
+        .overflow_policy(OverflowPolicy::Block);
+    for _ in 0..10 {
+        log_to(&mut writer, &Record::new(Level::Info, "slow"));
+    }
+
+    assert!(!writer.shutdown(Duration::from_millis(10)));
+}
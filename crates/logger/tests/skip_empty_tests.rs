@@ -0,0 +1,27 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{info, log, set_skip_empty, Field, Level, Record};
+
+#[test]
+fn test_empty_message_dropped_when_enabled() {
+    let fields: [Field; 1] = [("status", &200)];
+    let output = capture(|| {
+        set_skip_empty(true);
+        info("");
+        info("   ");
+        log(&Record::new(Level::Info, "").with_fields(&fields));
+        info("kept");
+        set_skip_empty(false);
+    });
+    assert_eq!(
+        output,
+        "00:00:00.000 info  status=200\n00:00:00.000 info kept\n"
+    );
+}
+
+#[test]
+fn test_empty_message_kept_by_default() {
+    let output = capture(|| info(""));
+    assert_eq!(output, "00:00:00.000 info \n");
+}
@@ -0,0 +1,42 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{info, span};
+
+#[test]
+fn test_span_logs_correlated_enter_and_exit() {
+    let output = capture(|| {
+        let _span = span!("handle_request", req_id = 42);
+        info("loading user");
+    });
+
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines.len(), 3, "{output}");
+    assert_eq!(lines[0], "00:00:00.000 info enter handle_request req_id=42");
+    assert_eq!(lines[1], "00:00:00.000 info loading user req_id=42");
+    assert!(
+        lines[2].starts_with("00:00:00.000 info exit handle_request req_id=42 duration="),
+        "{}",
+        lines[2]
+    );
+}
+
+#[test]
+fn test_fields_detached_after_span() {
+    let output = capture(|| {
+        {
+            let _outer = span!("job", id = 1);
+            let _inner = span!("step", name = "fetch");
+            info("working");
+        }
+        info("idle");
+    });
+
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines[1], "00:00:00.000 info enter step name=fetch id=1");
+    assert_eq!(lines[2], "00:00:00.000 info working id=1 name=fetch");
+    assert!(lines[3].starts_with("00:00:00.000 info exit step name=fetch duration="));
+    assert!(lines[3].ends_with(" id=1"));
+    assert!(lines[4].starts_with("00:00:00.000 info exit job id=1 duration="));
+    assert_eq!(lines[5], "00:00:00.000 info idle");
+}
@@ -0,0 +1,42 @@
+mod common;
+
+use common::{capture, lock};
+use rust_telemetry::{
+    clear_module_levels, clear_output, clear_timestamp, log_startup_info, set_format,
+    set_max_level, set_module_level, set_output, set_timestamp, Format, Level, MemoryWriter,
+};
+
+#[test]
+fn test_banner_reflects_config() {
+    let _guard = lock();
+    let buffer = MemoryWriter::new();
+    set_output(buffer.clone());
+    set_timestamp("00:00:00.000");
+    set_max_level(Level::Info);
+    set_module_level("db", Level::Debug);
+    set_format(Format::Pretty);
+
+    log_startup_info();
+
+    set_format(Format::Text);
+    clear_module_levels();
+    set_max_level(Level::Debug);
+    clear_timestamp();
+    clear_output();
+
+    assert_eq!(
+        buffer.contents(),
+        "00:00:00.000 info telemetry: level=info db=debug format=pretty output=MemoryWriter\n"
+    );
+}
+
+#[test]
+fn test_banner_respects_level() {
+    let output = capture(|| {
+        set_max_level(Level::Warn);
+        log_startup_info();
+        set_max_level(Level::Debug);
+    });
+
+    assert_eq!(output, "");
+}
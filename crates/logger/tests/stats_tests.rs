@@ -0,0 +1,31 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{debug, error, info, set_max_level, stats, warn, Level};
+
+#[test]
+fn test_stats_count_emitted_and_suppressed() {
+    capture(|| {
+        stats().reset();
+        set_max_level(Level::Warn);
+        debug("dropped");
+        info("dropped");
+        info("dropped");
+        warn("kept");
+        error("kept");
+        error("kept");
+        set_max_level(Level::Debug);
+    });
+
+    let stats = stats();
+    assert_eq!(stats.emitted(Level::Debug), 0);
+    assert_eq!(stats.emitted(Level::Info), 0);
+    assert_eq!(stats.emitted(Level::Warn), 1);
+    assert_eq!(stats.emitted(Level::Error), 2);
+    assert_eq!(stats.total_emitted(), 3);
+    assert_eq!(stats.suppressed_by_level(), 3);
+
+    stats.reset();
+    assert_eq!(stats.total_emitted(), 0);
+    assert_eq!(stats.suppressed_by_level(), 0);
+}
@@ -0,0 +1,39 @@
+mod common;
+
+use common::lock;
+use rust_telemetry::{clear_output, warn, Level, STATIC_MAX_LEVEL};
+use std::process::Command;
+
+/// Marker logged by `test_warn_to_default_output`
+const MARKER: &str = "stdout-warn-marker-41c9";
+
+/// Set in the child process, which does the actual logging
+const CHILD_ENV: &str = "RUST_TELEMETRY_STDOUT_CHILD";
+
+#[test]
+fn test_warn_to_default_output() {
+    if std::env::var_os(CHILD_ENV).is_none() {
+        return;
+    }
+    let _guard = lock();
+    clear_output();
+    warn!("{MARKER}");
+}
+
+#[test]
+fn test_warn_goes_to_stdout_not_stderr() {
+    if STATIC_MAX_LEVEL > Level::Warn {
+        return;
+    }
+    let output = Command::new(std::env::current_exe().unwrap())
+        .args(["--exact", "test_warn_to_default_output", "--test-threads=1"])
+        .env(CHILD_ENV, "1")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let line = stdout.lines().find(|line| line.contains(MARKER));
+    assert!(line.is_some_and(|line| line.contains("warn")), "{stdout:?}");
+    assert!(!stderr.contains(MARKER), "{stderr:?}");
+}
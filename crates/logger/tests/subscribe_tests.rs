@@ -0,0 +1,48 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{info, subscribe, warn, Level, SUBSCRIBER_CAPACITY};
+
+#[test]
+fn test_subscriber_receives_later_records() {
+    let mut records = Vec::new();
+    capture(|| {
+        info("before subscribing");
+        let receiver = subscribe();
+        info("first");
+        warn("second");
+        records.extend(receiver.try_iter());
+    });
+
+    let summary: Vec<_> = records
+        .iter()
+        .map(|record| (record.level(), record.message()))
+        .collect();
+    assert_eq!(summary, [(Level::Info, "first"), (Level::Warn, "second")]);
+}
+
+#[test]
+fn test_subscribers_are_independent() {
+    let mut counts = Vec::new();
+    capture(|| {
+        let receivers = [subscribe(), subscribe()];
+        info("shared");
+        counts.extend(receivers.iter().map(|receiver| receiver.try_iter().count()));
+    });
+
+    assert_eq!(counts, [1, 1]);
+}
+
+#[test]
+fn test_lagging_subscriber_does_not_block() {
+    let mut received = 0;
+    capture(|| {
+        let receiver = subscribe();
+        for i in 0..SUBSCRIBER_CAPACITY + 5 {
+            info(&format!("line {i}"));
+        }
+        received = receiver.try_iter().count();
+    });
+
+    assert_eq!(received, SUBSCRIBER_CAPACITY);
+}
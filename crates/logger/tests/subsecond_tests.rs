@@ -0,0 +1,34 @@
+mod common;
+
+use common::lock;
+use rust_telemetry::{
+    clear_clock, clear_output, info, set_fixed_time, set_output, set_timestamp_format,
+    set_timestamp_subsecond, MemoryWriter, TimestampFormat,
+};
+use std::time::{Duration, SystemTime};
+
+#[test]
+fn test_fraction_absent_when_disabled() {
+    let _guard = lock();
+    let buffer = MemoryWriter::new();
+    set_output(buffer.writer());
+    set_fixed_time(SystemTime::UNIX_EPOCH + Duration::from_millis(45_296_789));
+
+    set_timestamp_subsecond(false);
+    info("time of day");
+    set_timestamp_format(TimestampFormat::Rfc3339);
+    info("rfc3339");
+    set_timestamp_subsecond(true);
+    info("with fraction");
+
+    set_timestamp_format(TimestampFormat::TimeOfDay);
+    clear_clock();
+    clear_output();
+
+    assert_eq!(
+        buffer.contents(),
+        "12:34:56 info time of day\n\
+         1970-01-01T12:34:56Z info rfc3339\n\
+         1970-01-01T12:34:56.789Z info with fraction\n"
+    );
+}
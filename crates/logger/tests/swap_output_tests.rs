@@ -0,0 +1,64 @@
+mod common;
+
+use common::lock;
+use rust_telemetry::{clear_output, info, set_output, swap_output, with_output, MemoryWriter};
+use std::panic;
+
+#[test]
+fn test_swap_returns_previous_writer() {
+    let _guard = lock();
+    let original = MemoryWriter::new();
+    let temporary = MemoryWriter::new();
+    set_output(original.writer());
+
+    let previous = swap_output(temporary.writer());
+    info("captured");
+    swap_output(previous);
+    info("restored");
+
+    clear_output();
+    assert!(temporary.contents().ends_with("info captured\n"));
+    assert!(!temporary.contents().contains("restored"));
+    assert!(original.contents().ends_with("info restored\n"));
+    assert!(!original.contents().contains("captured"));
+}
+
+#[test]
+fn test_with_output_restores_after_scope() {
+    let _guard = lock();
+    let original = MemoryWriter::new();
+    let scoped = MemoryWriter::new();
+    set_output(original.writer());
+
+    let answer = with_output(scoped.writer(), || {
+        info("inside");
+        42
+    });
+    info("outside");
+
+    clear_output();
+    assert_eq!(answer, 42);
+    assert_eq!(scoped.contents().lines().count(), 1);
+    assert!(original.contents().ends_with("info outside\n"));
+}
+
+#[test]
+fn test_with_output_restores_on_panic() {
+    let _guard = lock();
+    let original = MemoryWriter::new();
+    let scoped = MemoryWriter::new();
+    set_output(original.writer());
+
+    let result = panic::catch_unwind(|| {
+        with_output(scoped.writer(), || {
+            info("before panic");
+            panic!("operation failed");
+        })
+    });
+    info("after panic");
+
+    clear_output();
+    assert!(result.is_err());
+    assert!(scoped.contents().ends_with("info before panic\n"));
+    assert!(original.contents().ends_with("info after panic\n"));
+}
@@ -0,0 +1,47 @@
+mod common;
+
+use common::{capture, compiled_in};
+use rust_telemetry::{clear_module_levels, set_max_level, set_module_level, Level, Record};
+
+#[test]
+fn test_target_matches_audit_filter() {
+    if !compiled_in(Level::Info) {
+        return;
+    }
+    let output = capture(|| {
+        set_max_level(Level::Error);
+        set_module_level("audit", Level::Info);
+        rust_telemetry::info!(target: "audit", "user {} logged in", 42);
+        rust_telemetry::info!("plain record");
+        clear_module_levels();
+        set_max_level(Level::Debug);
+    });
+
+    assert_eq!(output, "00:00:00.000 info user 42 logged in\n");
+}
+
+#[test]
+fn test_target_overrides_module_path() {
+    let record = Record::new(Level::Info, "msg")
+        .with_module_path("app::db")
+        .with_target("audit");
+    assert_eq!(record.target(), Some("audit"));
+    assert_eq!(record.module_path(), Some("app::db"));
+
+    let plain = Record::new(Level::Info, "msg").with_module_path("app::db");
+    assert_eq!(plain.target(), Some("app::db"));
+}
+
+#[test]
+fn test_module_prefix_matches_submodules_only() {
+    let output = capture(|| {
+        set_max_level(Level::Error);
+        set_module_level("app::db", Level::Debug);
+        rust_telemetry::log(&Record::new(Level::Info, "pool").with_module_path("app::db::pool"));
+        rust_telemetry::log(&Record::new(Level::Info, "dbx").with_module_path("app::dbx"));
+        clear_module_levels();
+        set_max_level(Level::Debug);
+    });
+
+    assert_eq!(output, "00:00:00.000 info pool\n");
+}
@@ -0,0 +1,50 @@
+mod common;
+
+use common::{capture, compiled_in};
+use rust_telemetry::{
+    debug, error, info, set_output, Level, LeveledWriter, MemoryWriter, TeeWriter,
+};
+
+#[test]
+fn test_branches_have_their_own_level() {
+    if !compiled_in(Level::Debug) {
+        return;
+    }
+    let console = MemoryWriter::new();
+    let file = MemoryWriter::new();
+    capture(|| {
+        set_output(
+            TeeWriter::new()
+                .branch(LeveledWriter::new(console.writer(), Level::Info))
+                .branch(LeveledWriter::new(file.writer(), Level::Debug)),
+        );
+        debug!("cache miss");
+        info!("request done");
+    });
+
+    assert_eq!(console.contents(), "00:00:00.000 info request done\n");
+    assert_eq!(
+        file.contents(),
+        "00:00:00.000 debg cache miss\n00:00:00.000 info request done\n"
+    );
+}
+
+#[test]
+fn test_tee_writes_every_branch() {
+    if !compiled_in(Level::Error) {
+        return;
+    }
+    let first = MemoryWriter::new();
+    let second = MemoryWriter::new();
+    capture(|| {
+        set_output(
+            TeeWriter::new()
+                .branch(first.writer())
+                .branch(second.writer()),
+        );
+        error!("boom");
+    });
+
+    assert_eq!(first.contents(), "00:00:00.000 errr boom\n");
+    assert_eq!(second.contents(), first.contents());
+}
@@ -0,0 +1,31 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{info, set_line_terminator, warn};
+
+#[test]
+fn test_crlf_terminator() {
+    let output = capture(|| {
+        set_line_terminator("\r\n");
+        info("first");
+        warn("second");
+        set_line_terminator("\n");
+    });
+
+    assert!(output.as_bytes().ends_with(b"\r\n"));
+    assert_eq!(
+        output,
+        "00:00:00.000 info first\r\n00:00:00.000 warn second\r\n"
+    );
+}
+
+#[test]
+fn test_empty_terminator() {
+    let output = capture(|| {
+        set_line_terminator("");
+        info("framed");
+        set_line_terminator("\n");
+    });
+
+    assert_eq!(output, "00:00:00.000 info framed");
+}
@@ -0,0 +1,37 @@
+mod common;
+
+use common::lock;
+use rust_telemetry::{clear_output, info, set_output, TestWriter};
+use std::process::Command;
+
+/// Marker logged by `test_logs_through_test_writer`
+const MARKER: &str = "test-writer-marker-7f3a";
+
+#[test]
+fn test_logs_through_test_writer() {
+    let _guard = lock();
+    set_output(TestWriter::new());
+    info(MARKER);
+    clear_output();
+}
+
+/// Run `test_logs_through_test_writer` in a child test process, returning its stdout
+fn run_child(extra: &[&str]) -> String {
+    let output = Command::new(std::env::current_exe().unwrap())
+        .args([
+            "--exact",
+            "test_logs_through_test_writer",
+            "--test-threads=1",
+        ])
+        .args(extra)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    String::from_utf8_lossy(&output.stdout).to_string()
+}
+
+#[test]
+fn test_passing_test_output_is_captured() {
+    assert!(!run_child(&[]).contains(MARKER));
+    assert!(run_child(&["--nocapture"]).contains(MARKER));
+}
@@ -0,0 +1,39 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{log, set_text_field_style, Field, Level, Record, TextFieldStyle};
+
+fn render(style: TextFieldStyle) -> String {
+    let fields: [Field; 2] = [("status", &200), ("path", &"/users \"me\"")];
+    capture(|| {
+        set_text_field_style(style);
+        log(&Record::new(Level::Info, "request").with_fields(&fields));
+        set_text_field_style(TextFieldStyle::KeyValue);
+    })
+}
+
+#[test]
+fn test_key_value_style() {
+    assert_eq!(
+        render(TextFieldStyle::KeyValue),
+        "00:00:00.000 info request status=200 path=/users \"me\"\n"
+    );
+}
+
+#[test]
+fn test_json_style() {
+    assert_eq!(
+        render(TextFieldStyle::Json),
+        "00:00:00.000 info request {\"status\":200,\"path\":\"/users \\\"me\\\"\"}\n"
+    );
+}
+
+#[test]
+fn test_json_style_without_fields() {
+    let output = capture(|| {
+        set_text_field_style(TextFieldStyle::Json);
+        log(&Record::new(Level::Info, "bare"));
+        set_text_field_style(TextFieldStyle::KeyValue);
+    });
+    assert_eq!(output, "00:00:00.000 info bare\n");
+}
@@ -0,0 +1,40 @@
+mod common;
+
+use common::capture_raw;
+use rust_telemetry::{
+    error, info, log, set_color_enabled, set_format, set_theme, Field, Format, Level, Record, Theme,
+};
+
+#[test]
+fn test_color_disabled_writes_no_escapes() {
+    let fields: [Field; 1] = [("code", &7)];
+    let output = capture_raw(|| {
+        set_color_enabled(false);
+        info("plain");
+        set_format(Format::Pretty);
+        log(&Record::new(Level::Error, "pretty").with_fields(&fields));
+        set_format(Format::Text);
+        set_color_enabled(true);
+    });
+
+    assert!(!output.contains('\x1b'), "{output:?}");
+    assert_eq!(
+        output,
+        "00:00:00.000 info plain\n00:00:00.000 errr pretty\n    code: 7\n"
+    );
+}
+
+#[test]
+fn test_custom_reset_sequence() {
+    let output = capture_raw(|| {
+        set_theme(Theme {
+            enabled: true,
+            reset: "\x1b[39m",
+            ..Theme::DEFAULT
+        });
+        error("boom");
+        set_theme(Theme::DEFAULT);
+    });
+
+    assert_eq!(output, "00:00:00.000 \x1b[31merrr\x1b[39m boom\n");
+}
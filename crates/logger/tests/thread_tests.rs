@@ -0,0 +1,61 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{info, set_show_thread, set_thread_id_format, ThreadDisplay, ThreadIdFormat};
+use std::thread;
+
+/// Log one line from a thread with the given name
+fn log_from(name: Option<&str>) {
+    let mut builder = thread::Builder::new();
+    if let Some(name) = name {
+        builder = builder.name(name.to_string());
+    }
+    builder.spawn(|| info("hello")).unwrap().join().unwrap();
+}
+
+/// The `[thread]` label of a line
+fn label(line: &str) -> &str {
+    let start = line.find('[').unwrap() + 1;
+    &line[start..line.find(']').unwrap()]
+}
+
+#[test]
+fn test_thread_id_is_plain_number() {
+    let output = capture(|| {
+        set_show_thread(Some(ThreadDisplay::Id));
+        log_from(Some("worker"));
+        set_thread_id_format(ThreadIdFormat::Hex);
+        log_from(None);
+        set_thread_id_format(ThreadIdFormat::Decimal);
+        set_show_thread(None);
+    });
+
+    let lines: Vec<&str> = output.lines().collect();
+    let decimal = label(lines[0]);
+    assert!(decimal.parse::<u64>().is_ok(), "{decimal}");
+    let hex = label(lines[1]);
+    assert!(
+        u64::from_str_radix(hex.trim_start_matches("0x"), 16).is_ok(),
+        "{hex}"
+    );
+    assert!(hex.starts_with("0x"));
+}
+
+#[test]
+fn test_thread_name_or_id() {
+    let output = capture(|| {
+        set_show_thread(Some(ThreadDisplay::NameOrId));
+        log_from(Some("worker"));
+        log_from(None);
+        set_show_thread(Some(ThreadDisplay::Name));
+        log_from(None);
+        set_show_thread(None);
+        info("hidden");
+    });
+
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines[0], "00:00:00.000 [worker] info hello");
+    assert!(label(lines[1]).parse::<u64>().is_ok());
+    assert_eq!(lines[2], "00:00:00.000 [unnamed] info hello");
+    assert_eq!(lines[3], "00:00:00.000 info hidden");
+}
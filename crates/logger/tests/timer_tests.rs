@@ -0,0 +1,46 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{timed, Level, Timer};
+use std::thread;
+use std::time::Duration;
+
+/// Parse the millisecond value out of `"<label> in <n>ms..."`
+fn elapsed_ms(line: &str) -> f64 {
+    let after = line.split(" in ").nth(1).unwrap();
+    let value = after.split("ms").next().unwrap();
+    value.parse().unwrap()
+}
+
+#[test]
+fn test_timed_block_logs_duration() {
+    let mut result = 0;
+    let output = capture(|| {
+        result = timed!("sleep", {
+            thread::sleep(Duration::from_millis(20));
+            7
+        });
+    });
+
+    assert_eq!(result, 7);
+    assert!(
+        output.starts_with("00:00:00.000 debg sleep in "),
+        "{output}"
+    );
+    let ms = elapsed_ms(output.trim_end());
+    assert!((20.0..5000.0).contains(&ms), "{output}");
+}
+
+#[test]
+fn test_timer_level_and_fields() {
+    let output = capture(|| {
+        let _timer = Timer::new("load")
+            .level(Level::Info)
+            .field("rows", 3)
+            .field("table", "users");
+        thread::sleep(Duration::from_millis(5));
+    });
+
+    assert!(output.starts_with("00:00:00.000 info load in "), "{output}");
+    assert!(output.ends_with(" rows=3 table=users\n"), "{output}");
+}
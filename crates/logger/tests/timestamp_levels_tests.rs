@@ -0,0 +1,43 @@
+mod common;
+
+use common::{capture, compiled_in};
+use rust_telemetry::{
+    debug, error, info, set_timestamp_levels, set_timestamp_position, warn, Level,
+    TimestampPosition,
+};
+
+#[test]
+fn test_debug_line_has_no_timestamp() {
+    if !compiled_in(Level::Debug) {
+        return;
+    }
+    let output = capture(|| {
+        set_timestamp_levels(Level::Info);
+        debug!("chatty");
+        info!("started");
+        error!("failed");
+        set_timestamp_levels(Level::Debug);
+    });
+
+    assert_eq!(
+        output,
+        "debg chatty\n00:00:00.000 info started\n00:00:00.000 errr failed\n"
+    );
+}
+
+#[test]
+fn test_levels_apply_to_suffix_timestamps() {
+    if !compiled_in(Level::Info) {
+        return;
+    }
+    let output = capture(|| {
+        set_timestamp_position(TimestampPosition::Suffix);
+        set_timestamp_levels(Level::Warn);
+        info!("quiet");
+        warn!("loud");
+        set_timestamp_levels(Level::Debug);
+        set_timestamp_position(TimestampPosition::Prefix);
+    });
+
+    assert_eq!(output, "info quiet\nwarn loud 00:00:00.000\n");
+}
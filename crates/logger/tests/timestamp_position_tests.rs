@@ -0,0 +1,53 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{
+    log, set_format, set_timestamp_position, Field, Format, Level, Record, TimestampPosition,
+};
+
+/// Log one record with a field in the current format
+fn log_sample() {
+    let fields: [Field; 1] = [("id", &7)];
+    log(&Record::new(Level::Info, "hello").with_fields(&fields));
+}
+
+#[test]
+fn test_timestamp_prefix() {
+    let output = capture(|| {
+        set_timestamp_position(TimestampPosition::Prefix);
+        log_sample();
+    });
+
+    assert_eq!(output, "00:00:00.000 info hello id=7\n");
+}
+
+#[test]
+fn test_timestamp_suffix() {
+    let output = capture(|| {
+        set_timestamp_position(TimestampPosition::Suffix);
+        log_sample();
+        set_format(Format::Pretty);
+        log_sample();
+        set_format(Format::Text);
+        set_timestamp_position(TimestampPosition::Prefix);
+    });
+
+    assert_eq!(
+        output,
+        "info hello id=7 00:00:00.000\ninfo hello 00:00:00.000\n    id: 7\n"
+    );
+}
+
+#[test]
+fn test_timestamp_none() {
+    let output = capture(|| {
+        set_timestamp_position(TimestampPosition::None);
+        log_sample();
+        set_format(Format::Pretty);
+        log_sample();
+        set_format(Format::Text);
+        set_timestamp_position(TimestampPosition::Prefix);
+    });
+
+    assert_eq!(output, "info hello id=7\ninfo hello\n    id: 7\n");
+}
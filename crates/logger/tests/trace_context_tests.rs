@@ -0,0 +1,33 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{info, log_kv, with_trace_context, Field, Level};
+
+#[test]
+fn test_ids_only_inside_scope() {
+    let output = capture(|| {
+        info("before");
+        with_trace_context("4bf92f35", "00f067aa", || {
+            let fields: [Field; 1] = [("path", &"/users")];
+            log_kv(Level::Info, "request", &fields);
+            with_trace_context("4bf92f35", "b7ad6b71", || info("child"));
+            info("parent again");
+        });
+        info("after");
+    });
+
+    assert_eq!(
+        output,
+        "00:00:00.000 info before\n\
+         00:00:00.000 info request path=/users trace_id=4bf92f35 span_id=00f067aa\n\
+         00:00:00.000 info child trace_id=4bf92f35 span_id=b7ad6b71\n\
+         00:00:00.000 info parent again trace_id=4bf92f35 span_id=00f067aa\n\
+         00:00:00.000 info after\n"
+    );
+}
+
+#[test]
+fn test_scope_returns_value() {
+    let value = with_trace_context("t", "s", || 42);
+    assert_eq!(value, 42);
+}
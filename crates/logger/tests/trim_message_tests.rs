@@ -0,0 +1,52 @@
+mod common;
+
+use common::{capture, compiled_in};
+use rust_telemetry::{info, set_escape_newlines, set_trim_message, Level};
+
+#[test]
+fn test_trailing_newline_produces_one_line() {
+    if !compiled_in(Level::Info) {
+        return;
+    }
+    let output = capture(|| info!("hi\n"));
+
+    assert_eq!(output, "00:00:00.000 info hi\n");
+}
+
+#[test]
+fn test_trailing_newline_with_escaping_disabled() {
+    if !compiled_in(Level::Info) {
+        return;
+    }
+    let output = capture(|| {
+        set_escape_newlines(false);
+        info!("hi\r\n \t");
+        set_escape_newlines(true);
+    });
+
+    assert_eq!(output, "00:00:00.000 info hi\n");
+}
+
+#[test]
+fn test_leading_whitespace_is_kept() {
+    if !compiled_in(Level::Info) {
+        return;
+    }
+    let output = capture(|| info!("  indented  "));
+
+    assert_eq!(output, "00:00:00.000 info   indented\n");
+}
+
+#[test]
+fn test_trimming_can_be_disabled() {
+    if !compiled_in(Level::Info) {
+        return;
+    }
+    let output = capture(|| {
+        set_trim_message(false);
+        info!("hi ");
+        set_trim_message(true);
+    });
+
+    assert_eq!(output, "00:00:00.000 info hi \n");
+}
@@ -0,0 +1,28 @@
+//! Exercises the console path on Windows CI (UTF-8 lines go out via `WriteConsoleW`)
+
+#![cfg(windows)]
+
+mod common;
+
+use common::lock;
+use rust_telemetry::{clear_output, info, set_output, stats, StdoutWriter};
+use std::io::Write;
+
+#[test]
+fn test_non_ascii_line_written() {
+    let mut writer = StdoutWriter::new();
+    writer.write_all("café naïve ✓ 日本\n".as_bytes()).unwrap();
+    writer.flush().unwrap();
+}
+
+#[test]
+fn test_logger_writes_non_ascii_without_failures() {
+    let _guard = lock();
+    stats().reset();
+    set_output(StdoutWriter::new());
+
+    info("überprüfung abgeschlossen ✓");
+
+    clear_output();
+    assert_eq!(stats().write_failures(), 0);
+}
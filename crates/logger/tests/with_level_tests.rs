@@ -0,0 +1,41 @@
+mod common;
+
+use common::{capture, compiled_in, lock};
+use rust_telemetry::{debug, info, max_level, set_max_level, with_level, Level};
+use std::panic;
+
+#[test]
+fn test_level_applies_inside_scope_only() {
+    if !compiled_in(Level::Debug) {
+        return;
+    }
+    let output = capture(|| {
+        set_max_level(Level::Warn);
+        let value = with_level(Level::Debug, || {
+            debug!("inside");
+            42
+        });
+        debug!("outside");
+        assert_eq!(value, 42);
+        assert_eq!(max_level(), Level::Warn);
+        set_max_level(Level::Debug);
+    });
+
+    assert_eq!(output, "00:00:00.000 debg inside\n");
+}
+
+#[test]
+fn test_level_restored_after_panic() {
+    let _guard = lock();
+    set_max_level(Level::Info);
+    let result = panic::catch_unwind(|| {
+        with_level(Level::Error, || {
+            info!("suppressed");
+            panic!("boom");
+        })
+    });
+
+    assert!(result.is_err());
+    assert_eq!(max_level(), Level::Info);
+    set_max_level(Level::Debug);
+}
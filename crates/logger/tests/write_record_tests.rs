@@ -0,0 +1,23 @@
+mod common;
+
+use common::capture;
+use rust_telemetry::{set_max_level, write_record, Level};
+
+#[test]
+fn test_write_record_uses_level_label() {
+    let output = capture(|| write_record(Level::Warn, "disk low"));
+
+    assert_eq!(output, "00:00:00.000 warn disk low\n");
+}
+
+#[test]
+fn test_write_record_is_filtered_by_max_level() {
+    let output = capture(|| {
+        set_max_level(Level::Error);
+        write_record(Level::Warn, "disk low");
+        write_record(Level::Error, "disk full");
+        set_max_level(Level::Debug);
+    });
+
+    assert_eq!(output, "00:00:00.000 errr disk full\n");
+}